@@ -1,20 +1,30 @@
-use std::{io, time::SystemTime};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::{
-    common::configs::{CHAR_LIST, RANDOMNESS_LENGTH},
+    common::configs::{ALPHABET, RANDOMNESS_LENGTH, TIMESTAMP_LENGTH},
     functions::{
+        binary::{_from_bytes, _to_bytes, FromBytesOptions, ToBytesOptions},
         decode::{_decode, DecodeOptions},
         encode::{_encode, EncodeOptions},
-        generate::{_generate, GenerateOptions},
+        generate::{_generate, _generate_typed, GenerateOptions},
         get_randomness::{_get_randomness, GetRandomnessOptions},
-        rowid::{_rowid, RowIDOptions},
-        verify::{_verify, VerifyOptions},
+        rowid::{_rowid, _rowid_typed, RowIDOptions},
+        verify::{_try_verify, _verify, VerifyOptions, VerifyPolicy},
     },
+    random::ThreadRandom,
+    time::{Precision, SystemClock},
 };
 
 pub use crate::{
-    common::errors::RowIDError,
-    functions::{generate::GenerateResult, verify::VerifyResult},
+    common::{
+        errors::RowIDError,
+        id::RowID,
+        prefixed_id::{PrefixedRowID, RowIDPrefix},
+    },
+    functions::{
+        generate::GenerateResult,
+        verify::{Verified, VerifyResult},
+    },
 };
 
 /// This function generates a 32-character unique ID
@@ -29,11 +39,58 @@ pub use crate::{
 /// ```
 pub fn rowid() -> String {
     _rowid(RowIDOptions {
-        char_list: CHAR_LIST,
+        alphabet: &ALPHABET,
+        randomness_length: RANDOMNESS_LENGTH,
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        clock: &SystemClock,
+        random_source: &ThreadRandom,
+    })
+}
+
+/// This function generates a 32-character unique ID
+/// as a `RowID`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{rowid_typed, RowID};
+///
+/// let id: RowID = rowid_typed();
+/// ```
+pub fn rowid_typed() -> RowID {
+    _rowid_typed(RowIDOptions {
+        alphabet: &ALPHABET,
         randomness_length: RANDOMNESS_LENGTH,
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        clock: &SystemClock,
+        random_source: &ThreadRandom,
     })
 }
 
+/// This function generates a 32-character unique ID
+/// with the prefix of the entity kind `T`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{PrefixedRowID, RowIDPrefix, rowid_prefixed};
+///
+/// struct User;
+///
+/// impl RowIDPrefix for User {
+///     const PREFIX: &'static str = "user_";
+/// }
+///
+/// let id: PrefixedRowID<User> = rowid_prefixed();
+/// ```
+pub fn rowid_prefixed<T: RowIDPrefix>() -> PrefixedRowID<T> {
+    PrefixedRowID::from_row_id_unchecked(rowid_typed().with_prefix(T::PREFIX))
+}
+
 /// This function encodes the timestamp in milliseconds
 /// into an ID without randomness.
 ///
@@ -45,10 +102,15 @@ pub fn rowid() -> String {
 ///
 /// let encoded: String = encode(SystemTime::now()).unwrap();
 /// ```
-pub fn encode<T: Into<SystemTime>>(system_time: T) -> io::Result<String> {
+pub fn encode<T: Into<SystemTime>>(
+    system_time: T
+) -> Result<String, RowIDError> {
     _encode(EncodeOptions {
-        char_list: CHAR_LIST,
+        alphabet: &ALPHABET,
         system_time: system_time.into(),
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
     })
 }
 
@@ -62,8 +124,14 @@ pub fn encode<T: Into<SystemTime>>(system_time: T) -> io::Result<String> {
 ///
 /// let decoded: SystemTime = decode("ABC123").unwrap();
 /// ```
-pub fn decode<S: AsRef<str>>(encoded: S) -> io::Result<SystemTime> {
-    _decode(DecodeOptions { char_list: CHAR_LIST, encoded: encoded.as_ref() })
+pub fn decode<S: AsRef<str>>(encoded: S) -> Result<SystemTime, RowIDError> {
+    _decode(DecodeOptions {
+        alphabet: &ALPHABET,
+        encoded: encoded.as_ref(),
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+    })
 }
 
 /// This function generates an ID based on the input.
@@ -77,17 +145,69 @@ pub fn decode<S: AsRef<str>>(encoded: S) -> io::Result<SystemTime> {
 /// let now: SystemTime = SystemTime::now();
 /// let result: GenerateResult = generate(now, Some(22));
 /// ```
+#[deprecated(since = "0.6.0", note = "Use `try_generate` instead")]
 pub fn generate<T: Into<SystemTime>>(
     system_time: T,
     randomness_length: Option<usize>,
 ) -> GenerateResult {
     _generate(GenerateOptions {
-        char_list: CHAR_LIST,
+        alphabet: &ALPHABET,
         system_time: system_time.into(),
         randomness_length: match randomness_length {
             | Some(l) => l,
             | None => RANDOMNESS_LENGTH,
         },
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        random_source: &ThreadRandom,
+    })
+}
+
+/// This function generates an ID based on the input.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::SystemTime;
+/// use rowid::base::try_generate;
+///
+/// let now: SystemTime = SystemTime::now();
+/// let id: String = try_generate(now, Some(22)).unwrap();
+/// ```
+pub fn try_generate<T: Into<SystemTime>>(
+    system_time: T,
+    randomness_length: Option<usize>,
+) -> Result<String, RowIDError> {
+    generate_typed(system_time, randomness_length).map(RowID::into_string)
+}
+
+/// This function generates an ID based on the input as a `RowID`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::SystemTime;
+/// use rowid::base::{generate_typed, RowID};
+///
+/// let now: SystemTime = SystemTime::now();
+/// let id: RowID = generate_typed(now, Some(22)).unwrap();
+/// ```
+pub fn generate_typed<T: Into<SystemTime>>(
+    system_time: T,
+    randomness_length: Option<usize>,
+) -> Result<RowID, RowIDError> {
+    _generate_typed(GenerateOptions {
+        alphabet: &ALPHABET,
+        system_time: system_time.into(),
+        randomness_length: match randomness_length {
+            | Some(l) => l,
+            | None => RANDOMNESS_LENGTH,
+        },
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        random_source: &ThreadRandom,
     })
 }
 
@@ -100,8 +220,41 @@ pub fn generate<T: Into<SystemTime>>(
 ///
 /// let result: VerifyResult = verify("ABC123");
 /// ```
+#[deprecated(since = "0.6.0", note = "Use `try_verify` instead")]
 pub fn verify<S: AsRef<str>>(encoded: S) -> VerifyResult {
-    _verify(VerifyOptions { char_list: CHAR_LIST, encoded: encoded.as_ref() })
+    _verify(VerifyOptions {
+        alphabet: &ALPHABET,
+        encoded: encoded.as_ref(),
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        randomness_length: RANDOMNESS_LENGTH,
+        clock: &SystemClock,
+        policy: &VerifyPolicy::new(),
+    })
+}
+
+/// This function verifies if the ID is valid,
+/// and returns the decoded time and whether it is natural.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{try_verify, Verified};
+///
+/// let verified: Verified = try_verify("ABC123").unwrap();
+/// ```
+pub fn try_verify<S: AsRef<str>>(encoded: S) -> Result<Verified, RowIDError> {
+    _try_verify(VerifyOptions {
+        alphabet: &ALPHABET,
+        encoded: encoded.as_ref(),
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        randomness_length: RANDOMNESS_LENGTH,
+        clock: &SystemClock,
+        policy: &VerifyPolicy::new(),
+    })
 }
 
 /// This function generates randomness.
@@ -115,7 +268,44 @@ pub fn verify<S: AsRef<str>>(encoded: S) -> VerifyResult {
 /// ```
 pub fn get_randomness(randomness_length: usize) -> String {
     _get_randomness(GetRandomnessOptions {
-        char_list: CHAR_LIST,
+        alphabet: &ALPHABET,
         randomness_length,
+        random_source: &ThreadRandom,
+    })
+}
+
+/// This function converts the ID into compact bytes,
+/// the order of the bytes is the same as the order of the ID.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{rowid, to_bytes};
+///
+/// let bytes: Vec<u8> = to_bytes(rowid()).unwrap();
+/// ```
+pub fn to_bytes<S: AsRef<str>>(encoded: S) -> Result<Vec<u8>, RowIDError> {
+    _to_bytes(ToBytesOptions {
+        alphabet: &ALPHABET,
+        length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        encoded: encoded.as_ref(),
+    })
+}
+
+/// This function converts the compact bytes back into the ID.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{from_bytes, rowid, to_bytes};
+///
+/// let bytes: Vec<u8> = to_bytes(rowid()).unwrap();
+/// let id: String = from_bytes(&bytes).unwrap();
+/// ```
+pub fn from_bytes<B: AsRef<[u8]>>(bytes: B) -> Result<String, RowIDError> {
+    _from_bytes(FromBytesOptions {
+        alphabet: &ALPHABET,
+        length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        bytes: bytes.as_ref(),
     })
 }
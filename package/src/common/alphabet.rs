@@ -0,0 +1,283 @@
+use std::{borrow::Cow, collections::HashMap, fmt, sync::Arc};
+
+use crate::common::errors::RowIDError;
+
+const NONE: u8 = u8::MAX;
+
+/// A precompiled list of characters used to encode and decode IDs.
+///
+/// ASCII characters are looked up with a 256-entry table,
+/// other characters are looked up with a hash map.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::Alphabet;
+///
+/// let alphabet: Alphabet = Alphabet::new("0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+///
+/// assert!(alphabet.index_of('A') == Some(10));
+/// ```
+#[derive(Clone)]
+pub struct Alphabet {
+    symbols: Cow<'static, str>,
+    length: usize,
+    lookup: [u8; 256],
+    sorted: bool,
+    chars: Option<Arc<[char]>>,
+    indices: Option<Arc<HashMap<char, usize>>>,
+}
+
+const fn ascii_lookup(bytes: &[u8]) -> [u8; 256] {
+    let mut lookup: [u8; 256] = [NONE; 256];
+    let mut index: usize = 0;
+
+    while index < bytes.len() {
+        lookup[bytes[index] as usize] = index as u8;
+        index += 1;
+    }
+
+    lookup
+}
+
+impl Alphabet {
+    /// Crockford's Base32, the default `char_list`,
+    /// it preserves the sort order with 5 bits per character.
+    pub const CROCKFORD32: Self =
+        Self::from_static("0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+
+    /// The Base58 alphabet used by Bitcoin,
+    /// it preserves the sort order with about 5.86 bits per character.
+    pub const BASE58_BITCOIN: Self = Self::from_static(
+        "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+    );
+
+    /// Digits, uppercase and lowercase letters,
+    /// it preserves the sort order with about 5.95 bits per character.
+    pub const BASE62: Self = Self::from_static(
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    );
+
+    /// Digits and lowercase letters,
+    /// it preserves the sort order with about 5.17 bits per character.
+    pub const BASE36_LOWER: Self =
+        Self::from_static("0123456789abcdefghijklmnopqrstuvwxyz");
+
+    /// The URL-safe Base64 alphabet of RFC 4648,
+    /// it does not preserve the sort order, with 6 bits per character.
+    pub const BASE64_URL: Self = Self::from_static(
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    );
+
+    /// The URL-safe Base64 characters in ASCII order,
+    /// it preserves the sort order with 6 bits per character.
+    pub const BASE64_URL_SORTED: Self = Self::from_static(
+        "-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz",
+    );
+
+    /// Lowercase hexadecimal digits,
+    /// it preserves the sort order with 4 bits per character.
+    pub const HEX_LOWER: Self = Self::from_static("0123456789abcdef");
+
+    /// Creates a new `Alphabet` from the list of characters.
+    pub fn new<S: Into<String>>(symbols: S) -> Self {
+        let symbols: String = symbols.into();
+
+        if symbols.is_ascii() && symbols.len() < NONE as usize {
+            return Self {
+                length: symbols.len(),
+                lookup: ascii_lookup(symbols.as_bytes()),
+                sorted: symbols.as_bytes().windows(2).all(|w| w[0] < w[1]),
+                symbols: Cow::Owned(symbols),
+                chars: None,
+                indices: None,
+            };
+        }
+
+        let chars: Vec<char> = symbols.chars().collect();
+
+        let mut alphabet: Self = Self {
+            length: chars.len(),
+            symbols: Cow::Owned(symbols),
+            lookup: [NONE; 256],
+            sorted: chars.windows(2).all(|w| w[0] < w[1]),
+            chars: None,
+            indices: None,
+        };
+
+        for (index, &c) in chars.iter().enumerate() {
+            alphabet.insert(c, index);
+        }
+
+        alphabet.chars = Some(chars.into());
+        alphabet
+    }
+
+    /// Creates a new `Alphabet` from a static list of ASCII characters,
+    /// it can be used in constants.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the list contains non-ASCII or duplicate characters.
+    pub const fn from_static(symbols: &'static str) -> Self {
+        let bytes: &[u8] = symbols.as_bytes();
+        let lookup: [u8; 256] = ascii_lookup(bytes);
+        let mut sorted: bool = true;
+        let mut index: usize = 0;
+
+        while index < bytes.len() {
+            assert!(bytes[index].is_ascii(), "Alphabet must be ASCII");
+            assert!(
+                lookup[bytes[index] as usize] as usize == index,
+                "Alphabet must not contain duplicate characters"
+            );
+
+            if index > 0 && bytes[index - 1] >= bytes[index] {
+                sorted = false;
+            }
+
+            index += 1;
+        }
+
+        Self {
+            symbols: Cow::Borrowed(symbols),
+            length: bytes.len(),
+            lookup,
+            sorted,
+            chars: None,
+            indices: None,
+        }
+    }
+
+    /// Adds aliases that are decoded as the characters they point to,
+    /// and optionally accepts the other case of every character.
+    ///
+    /// Characters of the alphabet always take precedence,
+    /// so a case variant never replaces them.
+    ///
+    /// An error is returned if an alias is already in the alphabet,
+    /// or if it points to a character that is not in the alphabet.
+    pub(crate) fn with_aliases(
+        mut self,
+        case_insensitive: bool,
+        aliases: &[(char, char)],
+    ) -> Result<Self, RowIDError> {
+        for &(alias, character) in aliases {
+            let index: usize = match self.index_of(character) {
+                | Some(i) if self.index_of(alias).is_none() => i,
+                | _ => {
+                    return Err(RowIDError::InvalidAlias { alias, character });
+                },
+            };
+
+            self.insert(alias, index);
+        }
+
+        if case_insensitive {
+            let sources: Vec<(char, usize)> = self
+                .symbols
+                .chars()
+                .chain(aliases.iter().map(|&(alias, _)| alias))
+                .filter_map(|c| self.index_of(c).map(|i| (c, i)))
+                .collect();
+
+            for (c, index) in sources {
+                for variant in c.to_lowercase().chain(c.to_uppercase()) {
+                    if self.index_of(variant).is_none() {
+                        self.insert(variant, index);
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn insert(
+        &mut self,
+        c: char,
+        index: usize,
+    ) {
+        if c.is_ascii() && index < NONE as usize {
+            self.lookup[c as usize] = index as u8;
+        } else {
+            Arc::make_mut(self.indices.get_or_insert_with(Default::default))
+                .insert(c, index);
+        }
+    }
+
+    /// Get the list of characters as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.symbols
+    }
+
+    /// Get the number of characters.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Tells whether the characters are sorted,
+    /// so that the IDs sort in the same order as their timestamps.
+    pub fn preserves_sort_order(&self) -> bool {
+        self.sorted
+    }
+
+    /// Get the number of bits encoded by each character.
+    pub fn bits_per_char(&self) -> f64 {
+        (self.length as f64).log2()
+    }
+
+    /// Tells whether the alphabet only contains ASCII characters.
+    pub fn is_ascii(&self) -> bool {
+        self.symbols.is_ascii()
+    }
+
+    /// Get the character at the index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn char_at(
+        &self,
+        index: usize,
+    ) -> char {
+        match &self.chars {
+            | Some(chars) => chars[index],
+            | None => self.symbols.as_bytes()[index] as char,
+        }
+    }
+
+    /// Get the index of the character,
+    /// or `None` if it is not in the alphabet.
+    pub fn index_of(
+        &self,
+        c: char,
+    ) -> Option<usize> {
+        if c.is_ascii() && self.lookup[c as usize] != NONE {
+            return Some(self.lookup[c as usize] as usize);
+        }
+
+        self.indices.as_ref().and_then(|indices| indices.get(&c).copied())
+    }
+}
+
+impl fmt::Debug for Alphabet {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("Alphabet").field(&self.symbols).finish()
+    }
+}
+
+impl PartialEq for Alphabet {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.symbols == other.symbols
+    }
+}
+
+impl Eq for Alphabet {}
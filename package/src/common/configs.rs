@@ -1,3 +1,16 @@
+use crate::common::alphabet::Alphabet;
+
 pub const CHAR_LIST: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 pub const TIMESTAMP_LENGTH: usize = 10;
 pub const RANDOMNESS_LENGTH: usize = 22;
+pub const MIN_CHAR_LIST_LENGTH: usize = 28;
+pub const MIN_ALPHABET_LENGTH: usize = 2;
+pub const ULID_RANDOMNESS_LENGTH: usize = 16;
+pub const ULID_TIMESTAMP_BITS: usize = 48;
+pub const UUID_RANDOMNESS_LENGTH: usize = 14;
+pub const NODE_LENGTH: usize = 2;
+pub const SEQUENCE_LENGTH: usize = 3;
+/// Seconds from the Unix epoch to `10000-01-01T00:00:00Z`.
+pub const YEAR_10000: u64 = 253_402_300_800;
+
+pub static ALPHABET: Alphabet = Alphabet::from_static(CHAR_LIST);
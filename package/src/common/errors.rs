@@ -1,33 +1,283 @@
+use std::{error::Error, fmt, io, time::Duration};
+
 /// Errors that may occur during the process.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RowIDError {
+    /// The system time is before the epoch.
     SystemTimeBeforeEpoch,
-    EncodedLength,
-    InvalidEncoded,
-    CharListLength,
+    /// The encoded ID does not have the expected length.
+    EncodedLength {
+        /// The expected number of characters.
+        expected: usize,
+        /// The actual number of characters.
+        actual: usize,
+    },
+    /// The encoded ID contains a character that is not in the `char_list`.
+    InvalidEncoded {
+        /// The offending character.
+        character: char,
+        /// The position of the character in the encoded ID.
+        position: usize,
+    },
+    /// The `char_list` is too short.
+    CharListLength {
+        /// The minimum number of characters.
+        expected: usize,
+        /// The actual number of characters.
+        actual: usize,
+    },
+    /// The randomness overflowed within the same timestamp.
+    RandomnessOverflow,
+    /// The length of the `char_list` is not a power of 2.
+    BinaryCharList {
+        /// The actual number of characters.
+        actual: usize,
+    },
+    /// The ID is wider than 128 bits.
+    BinaryWidth {
+        /// The number of bits required by the ID.
+        bits: usize,
+    },
+    /// The binary does not match the length of the configuration.
+    BinaryLength {
+        /// The number of bits of the configuration.
+        expected: usize,
+        /// The number of bits in the binary.
+        actual: usize,
+    },
+    /// The timestamp is too large to be represented.
+    TimestampOverflow {
+        /// The out-of-range timestamp,
+        /// or `None` if it is larger than `u128::MAX`.
+        timestamp: Option<u128>,
+    },
+    /// The `char_list` contains a duplicate character.
+    CharListDuplicate {
+        /// The duplicate character.
+        character: char,
+    },
+    /// The `char_list` contains a non-ASCII character.
+    CharListNotAscii {
+        /// The non-ASCII character.
+        character: char,
+    },
+    /// The `char_list` is not sorted.
+    CharListNotSorted {
+        /// The first character that is out of order.
+        character: char,
+        /// The position of the character in the `char_list`.
+        position: usize,
+    },
+    /// The alias is in the `char_list`,
+    /// or the character it points to is not.
+    InvalidAlias {
+        /// The alias.
+        alias: char,
+        /// The character that the alias points to.
+        character: char,
+    },
+    /// The time of the ID is later than the allowed future skew.
+    TimestampInFuture {
+        /// How far the time of the ID is ahead of the current time.
+        ahead: Duration,
+    },
+    /// The ID is younger than the minimum age.
+    TimestampTooNew {
+        /// The age of the ID.
+        age: Duration,
+    },
+    /// The ID is older than the maximum age.
+    TimestampTooOld {
+        /// The age of the ID.
+        age: Duration,
+    },
+    /// The `char_list` cannot be used with the checksum.
+    ChecksumCharList,
+    /// The check symbol does not match the rest of the ID.
+    ChecksumMismatch {
+        /// The check symbol computed from the rest of the ID.
+        expected: char,
+        /// The check symbol in the ID.
+        actual: char,
+    },
+    /// The ID does not start with the expected prefix.
+    PrefixMismatch {
+        /// The position of the first character that differs from the prefix.
+        position: usize,
+    },
+    /// The UUID is not a version 7 UUID.
+    UuidVersion {
+        /// The version of the UUID.
+        version: usize,
+    },
+    /// The node ID does not fit in the node length.
+    NodeIDOverflow {
+        /// The node ID.
+        node_id: u64,
+    },
+    /// The node ID environment variable is missing or not a valid integer.
+    NodeIDEnv,
+    /// The sequence overflowed within the same timestamp.
+    SequenceOverflow,
+    /// The sequence leaves no bits for the timestamp in 64 bits.
+    SequenceLength {
+        /// The largest sequence length.
+        max: usize,
+        /// The sequence length.
+        actual: usize,
+    },
 }
 
 impl RowIDError {
-    /// Get the error message as `&str`.
+    /// Get the error message as `&str`, without the context.
     pub fn as_str(&self) -> &str {
         match self {
             | Self::SystemTimeBeforeEpoch => {
-                "System time must not before the Unix epoch"
+                "System time must not be before the epoch"
+            },
+            | Self::EncodedLength { .. } => {
+                "Encoded length does not match the expected length"
+            },
+            | Self::InvalidEncoded { .. } => "Encoded is not valid",
+            | Self::CharListLength { .. } => "The char_list is too short",
+            | Self::RandomnessOverflow => {
+                "Randomness overflowed within the same timestamp"
+            },
+            | Self::BinaryCharList { .. } => {
+                "The length of char_list must be a power of 2 for binary"
+            },
+            | Self::BinaryWidth { .. } => {
+                "The ID is too wide to be converted into 128 bits"
+            },
+            | Self::BinaryLength { .. } => {
+                "Binary does not match the length of the configuration"
+            },
+            | Self::TimestampOverflow { .. } => {
+                "Timestamp is too large to be represented"
+            },
+            | Self::CharListDuplicate { .. } => {
+                "The char_list must not contain duplicate characters"
+            },
+            | Self::CharListNotAscii { .. } => {
+                "The char_list must only contain ASCII characters"
+            },
+            | Self::CharListNotSorted { .. } => {
+                "The char_list must be sorted to preserve the sort order"
+            },
+            | Self::InvalidAlias { .. } => {
+                "An alias must not be in the char_list and must point to a character in it"
             },
-            | Self::EncodedLength => "Encoded is not long enough to be decoded",
-            | Self::InvalidEncoded => "Encoded is not valid",
-            | Self::CharListLength => {
-                "The length of char_list must be longer or equal to 28"
+            | Self::TimestampInFuture { .. } => {
+                "Timestamp is later than the allowed future skew"
+            },
+            | Self::TimestampTooNew { .. } => {
+                "ID is younger than the minimum age"
+            },
+            | Self::TimestampTooOld { .. } => {
+                "ID is older than the maximum age"
+            },
+            | Self::ChecksumCharList => {
+                "The Crockford checksum requires 32 characters without its check symbols"
+            },
+            | Self::ChecksumMismatch { .. } => {
+                "The check symbol does not match the ID"
+            },
+            | Self::PrefixMismatch { .. } => {
+                "The ID does not start with the expected prefix"
+            },
+            | Self::UuidVersion { .. } => "The UUID must be a version 7 UUID",
+            | Self::NodeIDOverflow { .. } => {
+                "The node ID is too large for the node length"
+            },
+            | Self::NodeIDEnv => {
+                "The node ID environment variable is missing or not a valid integer"
+            },
+            | Self::SequenceOverflow => {
+                "Sequence overflowed within the same timestamp"
+            },
+            | Self::SequenceLength { .. } => {
+                "The sequence is too long to be packed with the timestamp"
             },
         }
     }
 }
 
-impl std::fmt::Display for RowIDError {
+impl fmt::Display for RowIDError {
     fn fmt(
         &self,
-        f: &mut std::fmt::Formatter<'_>,
-    ) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(self.as_str())?;
+
+        match self {
+            | Self::SystemTimeBeforeEpoch
+            | Self::RandomnessOverflow
+            | Self::ChecksumCharList
+            | Self::NodeIDEnv
+            | Self::SequenceOverflow => Ok(()),
+            | Self::EncodedLength { expected, actual }
+            | Self::CharListLength { expected, actual }
+            | Self::BinaryLength { expected, actual } => {
+                write!(f, " (expected {}, got {})", expected, actual)
+            },
+            | Self::InvalidEncoded { character, position } => {
+                write!(f, " ({:?} at position {})", character, position)
+            },
+            | Self::BinaryCharList { actual }
+            | Self::UuidVersion { version: actual } => {
+                write!(f, " (got {})", actual)
+            },
+            | Self::BinaryWidth { bits } => write!(f, " ({} bits)", bits),
+            | Self::NodeIDOverflow { node_id } => {
+                write!(f, " (got {})", node_id)
+            },
+            | Self::TimestampOverflow { timestamp: Some(timestamp) } => {
+                write!(f, " ({})", timestamp)
+            },
+            | Self::TimestampOverflow { timestamp: None } => {
+                f.write_str(" (larger than u128)")
+            },
+            | Self::CharListDuplicate { character }
+            | Self::CharListNotAscii { character } => {
+                write!(f, " ({:?})", character)
+            },
+            | Self::CharListNotSorted { character, position } => {
+                write!(f, " ({:?} at position {})", character, position)
+            },
+            | Self::InvalidAlias { alias, character } => {
+                write!(f, " ({:?} -> {:?})", alias, character)
+            },
+            | Self::TimestampInFuture { ahead } => {
+                write!(f, " ({:?} ahead)", ahead)
+            },
+            | Self::TimestampTooNew { age } | Self::TimestampTooOld { age } => {
+                write!(f, " ({:?} old)", age)
+            },
+            | Self::ChecksumMismatch { expected, actual } => {
+                write!(f, " (expected {:?}, got {:?})", expected, actual)
+            },
+            | Self::PrefixMismatch { position } => {
+                write!(f, " (at position {})", position)
+            },
+            | Self::SequenceLength { max, actual } => {
+                write!(f, " (at most {}, got {})", max, actual)
+            },
+        }
+    }
+}
+
+impl Error for RowIDError {}
+
+impl From<RowIDError> for io::Error {
+    fn from(error: RowIDError) -> Self {
+        let kind: io::ErrorKind = match error {
+            | RowIDError::RandomnessOverflow | RowIDError::SequenceOverflow => {
+                io::ErrorKind::Other
+            },
+            | _ => io::ErrorKind::InvalidInput,
+        };
+
+        io::Error::new(kind, error)
     }
 }
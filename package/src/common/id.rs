@@ -0,0 +1,247 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    common::{
+        configs::{ALPHABET, RANDOMNESS_LENGTH, TIMESTAMP_LENGTH},
+        errors::RowIDError,
+    },
+    functions::parse::{_parse, ParseOptions},
+    time::Precision,
+};
+
+/// A strongly-typed RowID.
+///
+/// Comparison, ordering and hashing are based on the string form,
+/// so sorting `RowID` values sorts them by time.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::SystemTime;
+/// use rowid::base::RowID;
+///
+/// let id: RowID = "01JVS2Y5R80000000000000000000000".parse().unwrap();
+/// let timestamp: SystemTime = id.timestamp();
+/// let randomness: &str = id.randomness();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowID {
+    value: String,
+    timestamp: SystemTime,
+    timestamp_length: usize,
+    prefix_length: usize,
+    node_length: usize,
+    checksum: bool,
+}
+
+impl RowID {
+    pub(crate) fn from_parts(
+        value: String,
+        timestamp: SystemTime,
+        timestamp_length: usize,
+    ) -> Self {
+        Self {
+            value,
+            timestamp,
+            timestamp_length,
+            prefix_length: 0,
+            node_length: 0,
+            checksum: false,
+        }
+    }
+
+    pub(crate) fn with_prefix(
+        mut self,
+        prefix: &str,
+    ) -> Self {
+        self.value.insert_str(0, prefix);
+        self.prefix_length += prefix.len();
+        self
+    }
+
+    /// Inserts the encoded node ID between the timestamp and the randomness.
+    pub(crate) fn with_node(
+        mut self,
+        node: &str,
+    ) -> Self {
+        self.value.insert_str(self.split_index(), node);
+        self.with_node_length(node.chars().count())
+    }
+
+    pub(crate) fn with_node_length(
+        mut self,
+        node_length: usize,
+    ) -> Self {
+        self.node_length = node_length;
+        self
+    }
+
+    pub(crate) fn with_checksum(
+        mut self,
+        check: char,
+    ) -> Self {
+        self.value.push(check);
+        self.checksum = true;
+        self
+    }
+
+    /// Get the ID as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the prefix of the ID,
+    /// or an empty string if the configuration has no prefix.
+    pub fn prefix(&self) -> &str {
+        &self.value[..self.prefix_length]
+    }
+
+    /// Get the decoded timestamp part of the ID.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// Get the encoded timestamp part of the ID.
+    pub fn encoded_timestamp(&self) -> &str {
+        &self.value[self.prefix_length..self.split_index()]
+    }
+
+    /// Get the encoded node ID part of the ID,
+    /// or an empty string if the configuration has no node ID.
+    pub fn encoded_node(&self) -> &str {
+        let start: usize = self.split_index();
+
+        &self.value[start..self.node_end().max(start)]
+    }
+
+    /// Get the randomness part of the ID, without the check symbol.
+    pub fn randomness(&self) -> &str {
+        let end: usize = match self.checksum() {
+            | Some(check) => self.value.len() - check.len_utf8(),
+            | None => self.value.len(),
+        };
+
+        &self.value[self.node_end().min(end)..end]
+    }
+
+    /// Get the check symbol of the ID,
+    /// or `None` if the configuration has no checksum.
+    pub fn checksum(&self) -> Option<char> {
+        if self.checksum { self.value.chars().last() } else { None }
+    }
+
+    /// Convert the ID into `String`.
+    pub fn into_string(self) -> String {
+        self.value
+    }
+
+    /// Whether the ID without the prefix follows the default layout,
+    /// a 10-character timestamp and a 22-character randomness,
+    /// without a node ID or a checksum.
+    #[cfg(feature = "serde")]
+    pub(crate) fn has_default_layout(&self) -> bool {
+        self.timestamp_length == TIMESTAMP_LENGTH
+            && self.node_length == 0
+            && !self.checksum
+            && self.value.len() - self.prefix_length
+                == TIMESTAMP_LENGTH + RANDOMNESS_LENGTH
+    }
+
+    fn split_index(&self) -> usize {
+        self.value[self.prefix_length..]
+            .char_indices()
+            .nth(self.timestamp_length)
+            .map_or(self.value.len(), |(i, _)| self.prefix_length + i)
+    }
+
+    fn node_end(&self) -> usize {
+        let start: usize = self.split_index();
+
+        self.value[start..]
+            .char_indices()
+            .nth(self.node_length)
+            .map_or(self.value.len(), |(i, _)| start + i)
+    }
+}
+
+/// Parses a 32-character ID of the default configuration,
+/// every character must be in the default `char_list`.
+impl FromStr for RowID {
+    type Err = RowIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        _parse(ParseOptions {
+            alphabet: &ALPHABET,
+            encoded: s,
+            epoch: UNIX_EPOCH,
+            precision: Precision::Milliseconds,
+            timestamp_length: TIMESTAMP_LENGTH,
+            length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        })
+    }
+}
+
+impl fmt::Display for RowID {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+impl AsRef<str> for RowID {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl From<RowID> for String {
+    fn from(id: RowID) -> Self {
+        id.value
+    }
+}
+
+impl PartialEq for RowID {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for RowID {}
+
+impl PartialOrd for RowID {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RowID {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl Hash for RowID {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.value.hash(state);
+    }
+}
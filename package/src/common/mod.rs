@@ -1,2 +1,7 @@
+pub mod alphabet;
 pub mod configs;
 pub mod errors;
+pub mod id;
+pub mod prefixed_id;
+#[cfg(feature = "serde")]
+pub mod serde_impls;
@@ -0,0 +1,213 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    common::{
+        configs::{ALPHABET, RANDOMNESS_LENGTH, TIMESTAMP_LENGTH},
+        errors::RowIDError,
+        id::RowID,
+    },
+    functions::{
+        parse::{_parse, ParseOptions},
+        prefix::{_strip_prefix, PrefixOptions},
+    },
+    time::Precision,
+};
+
+/// A marker of an entity kind that declares the prefix of its IDs.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::RowIDPrefix;
+///
+/// struct User;
+///
+/// impl RowIDPrefix for User {
+///     const PREFIX: &'static str = "user_";
+/// }
+/// ```
+pub trait RowIDPrefix {
+    /// The prefix of the IDs, including the separator.
+    const PREFIX: &'static str;
+}
+
+/// A `RowID` whose prefix is checked against the entity kind `T`,
+/// so that IDs of different kinds cannot be mixed up.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::base::{PrefixedRowID, RowIDPrefix, rowid_prefixed};
+///
+/// struct User;
+///
+/// impl RowIDPrefix for User {
+///     const PREFIX: &'static str = "user_";
+/// }
+///
+/// let id: PrefixedRowID<User> = rowid_prefixed();
+/// let parsed: PrefixedRowID<User> = id.as_str().parse().unwrap();
+///
+/// assert!(id == parsed);
+/// ```
+pub struct PrefixedRowID<T: RowIDPrefix> {
+    id: RowID,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: RowIDPrefix> PrefixedRowID<T> {
+    pub(crate) fn from_row_id_unchecked(id: RowID) -> Self {
+        Self { id, marker: PhantomData }
+    }
+
+    /// Get the ID as `&str`, including the prefix.
+    pub fn as_str(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// Get the ID as `&RowID`.
+    pub fn as_row_id(&self) -> &RowID {
+        &self.id
+    }
+
+    /// Get the decoded timestamp part of the ID.
+    pub fn timestamp(&self) -> SystemTime {
+        self.id.timestamp()
+    }
+
+    /// Convert the ID into `RowID`.
+    pub fn into_row_id(self) -> RowID {
+        self.id
+    }
+
+    /// Convert the ID into `String`, including the prefix.
+    pub fn into_string(self) -> String {
+        self.id.into_string()
+    }
+}
+
+impl<T: RowIDPrefix> TryFrom<RowID> for PrefixedRowID<T> {
+    type Error = RowIDError;
+
+    fn try_from(id: RowID) -> Result<Self, Self::Error> {
+        let rest: &str = _strip_prefix(PrefixOptions {
+            prefix: T::PREFIX,
+            encoded: id.prefix(),
+        })?;
+
+        if !rest.is_empty() {
+            return Err(RowIDError::PrefixMismatch {
+                position: T::PREFIX.chars().count(),
+            });
+        }
+
+        Ok(Self::from_row_id_unchecked(id))
+    }
+}
+
+impl<T: RowIDPrefix> FromStr for PrefixedRowID<T> {
+    type Err = RowIDError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let encoded: &str =
+            _strip_prefix(PrefixOptions { prefix: T::PREFIX, encoded: s })?;
+
+        let id: RowID = _parse(ParseOptions {
+            alphabet: &ALPHABET,
+            encoded,
+            epoch: UNIX_EPOCH,
+            precision: Precision::Milliseconds,
+            timestamp_length: TIMESTAMP_LENGTH,
+            length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        })?;
+
+        Ok(Self::from_row_id_unchecked(id.with_prefix(T::PREFIX)))
+    }
+}
+
+impl<T: RowIDPrefix> fmt::Debug for PrefixedRowID<T> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_tuple("PrefixedRowID").field(&self.id).finish()
+    }
+}
+
+impl<T: RowIDPrefix> fmt::Display for PrefixedRowID<T> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<T: RowIDPrefix> Clone for PrefixedRowID<T> {
+    fn clone(&self) -> Self {
+        Self::from_row_id_unchecked(self.id.clone())
+    }
+}
+
+impl<T: RowIDPrefix> AsRef<str> for PrefixedRowID<T> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: RowIDPrefix> From<PrefixedRowID<T>> for RowID {
+    fn from(id: PrefixedRowID<T>) -> Self {
+        id.id
+    }
+}
+
+impl<T: RowIDPrefix> From<PrefixedRowID<T>> for String {
+    fn from(id: PrefixedRowID<T>) -> Self {
+        id.into_string()
+    }
+}
+
+impl<T: RowIDPrefix> PartialEq for PrefixedRowID<T> {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T: RowIDPrefix> Eq for PrefixedRowID<T> {}
+
+impl<T: RowIDPrefix> PartialOrd for PrefixedRowID<T> {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: RowIDPrefix> Ord for PrefixedRowID<T> {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl<T: RowIDPrefix> Hash for PrefixedRowID<T> {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.id.hash(state);
+    }
+}
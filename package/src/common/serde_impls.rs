@@ -0,0 +1,286 @@
+use std::{borrow::Cow, fmt};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, SeqAccess, Visitor},
+    ser,
+};
+
+use crate::{
+    common::{
+        configs::{ALPHABET, RANDOMNESS_LENGTH, TIMESTAMP_LENGTH},
+        id::RowID,
+        prefixed_id::{PrefixedRowID, RowIDPrefix},
+    },
+    functions::binary::{
+        _from_bytes, _to_bytes, FromBytesOptions, ToBytesOptions,
+    },
+    with_config::RowIDWithConfigResult,
+};
+
+/// The serialized form of an ID,
+/// the string in human-readable formats and the bytes in binary formats.
+#[doc(hidden)]
+pub enum Encoded<'de> {
+    Text(Cow<'de, str>),
+    Bytes(Cow<'de, [u8]>),
+}
+
+struct EncodedVisitor;
+
+impl<'de> Visitor<'de> for EncodedVisitor {
+    type Value = Encoded<'de>;
+
+    fn expecting(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        f.write_str("a RowID as a string or bytes")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(
+        self,
+        v: &'de str,
+    ) -> Result<Self::Value, E> {
+        Ok(Encoded::Text(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E: de::Error>(
+        self,
+        v: &str,
+    ) -> Result<Self::Value, E> {
+        Ok(Encoded::Text(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(
+        self,
+        v: &'de [u8],
+    ) -> Result<Self::Value, E> {
+        Ok(Encoded::Bytes(Cow::Borrowed(v)))
+    }
+
+    fn visit_bytes<E: de::Error>(
+        self,
+        v: &[u8],
+    ) -> Result<Self::Value, E> {
+        Ok(Encoded::Bytes(Cow::Owned(v.to_vec())))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error> {
+        let mut bytes: Vec<u8> =
+            Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        Ok(Encoded::Bytes(Cow::Owned(bytes)))
+    }
+}
+
+/// Deserializes the string in human-readable formats
+/// or when `compact` is `false`, and the bytes otherwise.
+#[doc(hidden)]
+pub fn deserialize_encoded<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    compact: bool,
+) -> Result<Encoded<'de>, D::Error> {
+    if deserializer.is_human_readable() || !compact {
+        deserializer.deserialize_str(EncodedVisitor)
+    } else {
+        deserializer.deserialize_bytes(EncodedVisitor)
+    }
+}
+
+/// Serializes the ID with the configuration,
+/// the bytes are used in binary formats
+/// if the length of the `char_list` is a power of 2.
+#[doc(hidden)]
+pub fn serialize_with_config<S: Serializer>(
+    config: &RowIDWithConfigResult,
+    id: &RowID,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() || !is_compact(config) {
+        return serializer.serialize_str(id.as_str());
+    }
+
+    let bytes: Vec<u8> =
+        config.to_bytes(id.as_str()).map_err(ser::Error::custom)?;
+
+    serializer.serialize_bytes(&bytes)
+}
+
+/// Deserializes the ID with the configuration,
+/// it is validated with the same rules as `parse`.
+#[doc(hidden)]
+pub fn deserialize_with_config<'de, D: Deserializer<'de>>(
+    config: &RowIDWithConfigResult,
+    deserializer: D,
+) -> Result<RowID, D::Error> {
+    let encoded: String =
+        match deserialize_encoded(deserializer, is_compact(config))? {
+            | Encoded::Text(text) => text.into_owned(),
+            | Encoded::Bytes(bytes) => {
+                config.from_bytes(bytes).map_err(de::Error::custom)?
+            },
+        };
+
+    config.parse(encoded).map_err(de::Error::custom)
+}
+
+fn is_compact(config: &RowIDWithConfigResult) -> bool {
+    config.alphabet().len().is_power_of_two()
+}
+
+/// Converts the ID without the prefix into the compact bytes,
+/// or returns an error if it does not follow the default configuration,
+/// so every serialized ID can be deserialized.
+fn to_default_bytes<E: ser::Error>(
+    id: &RowID,
+    prefix_length: usize,
+) -> Result<Vec<u8>, E> {
+    if !id.has_default_layout() {
+        return Err(ser::Error::custom(
+            "RowID does not follow the default configuration, \
+             use `rowid_type!` for other configurations",
+        ));
+    }
+
+    _to_bytes(ToBytesOptions {
+        alphabet: &ALPHABET,
+        length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        encoded: &id.as_str()[prefix_length..],
+    })
+    .map_err(ser::Error::custom)
+}
+
+fn from_default_bytes<E: de::Error>(bytes: &[u8]) -> Result<String, E> {
+    _from_bytes(FromBytesOptions {
+        alphabet: &ALPHABET,
+        length: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+        bytes,
+    })
+    .map_err(de::Error::custom)
+}
+
+/// The string is used in human-readable formats,
+/// and the 20 compact bytes are used in binary formats.
+///
+/// Only IDs of the default configuration can be serialized,
+/// the same IDs that `Deserialize` accepts.
+impl Serialize for RowID {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if !self.prefix().is_empty() {
+            return Err(ser::Error::custom(
+                "RowID with a prefix cannot be serialized, \
+                 use `PrefixedRowID` or `rowid_type!` instead",
+            ));
+        }
+
+        let bytes: Vec<u8> = to_default_bytes(self, 0)?;
+
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+/// The ID is validated with the same rules as `FromStr`.
+impl<'de> Deserialize<'de> for RowID {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<Self, D::Error> {
+        match deserialize_encoded(deserializer, true)? {
+            | Encoded::Text(text) => text.parse(),
+            | Encoded::Bytes(bytes) => from_default_bytes(&bytes)?.parse(),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+/// The string with the prefix is used in human-readable formats,
+/// and the compact bytes without the prefix are used in binary formats.
+///
+/// Only IDs whose rest follows the default configuration
+/// can be serialized, the same IDs that `Deserialize` accepts.
+impl<T: RowIDPrefix> Serialize for PrefixedRowID<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes: Vec<u8> =
+            to_default_bytes(self.as_row_id(), T::PREFIX.len())?;
+
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.as_str())
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+/// The ID is validated with the same rules as `FromStr`.
+impl<'de, T: RowIDPrefix> Deserialize<'de> for PrefixedRowID<T> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<Self, D::Error> {
+        match deserialize_encoded(deserializer, true)? {
+            | Encoded::Text(text) => text.parse(),
+            | Encoded::Bytes(bytes) => {
+                let encoded: String = from_default_bytes(&bytes)?;
+
+                format!("{}{}", T::PREFIX, encoded).parse()
+            },
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the epoch as signed milliseconds since the Unix epoch,
+/// so an epoch before 1970 is negative.
+#[doc(hidden)]
+pub mod epoch_millis {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use serde::{Deserialize, Deserializer, Serializer, de, ser};
+
+    pub fn serialize<S: Serializer>(
+        epoch: &SystemTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let millis: Option<i64> = match epoch.duration_since(UNIX_EPOCH) {
+            | Ok(since) => i64::try_from(since.as_millis()).ok(),
+            | Err(e) => i64::try_from(e.duration().as_millis())
+                .ok()
+                .map(|before| -before),
+        };
+
+        match millis {
+            | Some(millis) => serializer.serialize_i64(millis),
+            | None => Err(ser::Error::custom("epoch is out of range")),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D
+    ) -> Result<SystemTime, D::Error> {
+        let millis: i64 = i64::deserialize(deserializer)?;
+        let offset: Duration = Duration::from_millis(millis.unsigned_abs());
+
+        if millis < 0 {
+            UNIX_EPOCH.checked_sub(offset)
+        } else {
+            UNIX_EPOCH.checked_add(offset)
+        }
+        .ok_or_else(|| de::Error::custom("epoch is out of range"))
+    }
+}
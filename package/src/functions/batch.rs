@@ -0,0 +1,301 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    functions::{
+        encode::{encode_fixed, saturate_timestamp},
+        monotonic::increment_indices,
+    },
+    time::system_time_to_timestamp_since,
+    with_config::RowIDWithConfigResult,
+};
+
+/// An endless iterator of IDs for bulk generation.
+///
+/// The clock is first read by the first ID,
+/// then once per timestamp unit of the precision,
+/// once per millisecond by default,
+/// and the IDs in between share the same timestamp.
+///
+/// One buffer is reused for every ID,
+/// `next` clones it into a new `String`,
+/// and `next_into` copies it into a `String` of the caller instead.
+///
+/// With `monotonic`, the randomness is incremented by one for each ID
+/// until the timestamp moves forward.
+/// When it overflows, the iterator waits for the next timestamp
+/// for up to one timestamp unit, and at least one millisecond,
+/// and ends if the clock has not moved forward by then.
+///
+/// The timestamp saturates at `max_system_time`,
+/// a monotonic iterator ends when it cannot move past it.
+///
+/// The iterator ends when the clock reads a time before the epoch,
+/// where the generators return `SystemTimeBeforeEpoch`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{
+///     RowIDIter, RowIDWithConfig, RowIDWithConfigResult,
+/// };
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().done().unwrap();
+/// let iter: RowIDIter = rwc.rowid_iter().monotonic(true);
+///
+/// let ids: Vec<String> = iter.take(1_000).collect();
+///
+/// assert!(ids.windows(2).all(|w| w[0] < w[1]));
+/// ```
+#[derive(Debug)]
+pub struct RowIDIter<'a> {
+    config: &'a RowIDWithConfigResult,
+    prefix_length: usize,
+    monotonic: bool,
+    timestamp: u128,
+    interval: Duration,
+    read_at: Instant,
+    buffer: String,
+    head_length: usize,
+    indices: Vec<usize>,
+    started: bool,
+}
+
+impl<'a> RowIDIter<'a> {
+    pub(crate) fn new(config: &'a RowIDWithConfigResult) -> Self {
+        let prefix: &str = config.state.prefix.as_deref().unwrap_or_default();
+
+        Self {
+            config,
+            prefix_length: prefix.len(),
+            monotonic: false,
+            timestamp: 0,
+            interval: config
+                .state
+                .precision
+                .timestamp_to_duration(1)
+                .unwrap_or_default(),
+            read_at: Instant::now(),
+            buffer: String::from(prefix),
+            head_length: 0,
+            indices: vec![0; config.state.randomness_length],
+            started: false,
+        }
+    }
+
+    /// Makes every ID greater than the previous one.
+    ///
+    /// default: `false`
+    pub fn monotonic(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.monotonic = enabled;
+        self
+    }
+
+    /// The timestamp saturates at the largest one that can be encoded,
+    /// and is `None` if the clock is before the epoch.
+    fn now(&mut self) -> Option<u128> {
+        self.read_at = Instant::now();
+
+        system_time_to_timestamp_since(
+            self.config.state.clock.now(),
+            self.config.state.epoch,
+            self.config.state.precision,
+        )
+        .map(|timestamp| {
+            saturate_timestamp(
+                self.config.alphabet(),
+                timestamp,
+                self.config.state.timestamp_length,
+            )
+        })
+    }
+
+    /// Encodes the timestamp and the node ID after the prefix.
+    fn set_timestamp(
+        &mut self,
+        timestamp: u128,
+    ) {
+        self.timestamp = timestamp;
+        self.buffer.truncate(self.prefix_length);
+        self.buffer.push_str(&encode_fixed(
+            self.config.alphabet(),
+            timestamp,
+            self.config.state.timestamp_length,
+        ));
+        self.buffer.push_str(self.config.encoded_node().unwrap_or_default());
+        self.head_length = self.buffer.len();
+    }
+
+    /// Reads the clock again once a timestamp unit has passed,
+    /// and returns whether the timestamp changed,
+    /// it only moves forward with `monotonic`.
+    ///
+    /// Returns `None` if the clock is before the epoch.
+    fn refresh_timestamp(&mut self) -> Option<bool> {
+        if self.read_at.elapsed() < self.interval {
+            return Some(false);
+        }
+
+        let timestamp: u128 = self.now()?;
+
+        let changed: bool = if self.monotonic {
+            timestamp > self.timestamp
+        } else {
+            timestamp != self.timestamp
+        };
+
+        if changed {
+            self.set_timestamp(timestamp);
+        }
+
+        Some(changed)
+    }
+
+    fn fill_randomness(&mut self) {
+        self.config
+            .state
+            .random_source
+            .fill_indices(self.config.alphabet().len(), &mut self.indices);
+    }
+
+    /// Waits for the clock to pass the current timestamp,
+    /// or returns `false` if it is the largest one that can be encoded
+    /// or the clock has not moved forward within the wait
+    /// or is before the epoch.
+    fn next_timestamp(&mut self) -> bool {
+        let max: u128 = saturate_timestamp(
+            self.config.alphabet(),
+            u128::MAX,
+            self.config.state.timestamp_length,
+        );
+
+        if self.timestamp >= max {
+            return false;
+        }
+
+        let started_at: Instant = Instant::now();
+        let wait: Duration = self.interval.max(Duration::from_millis(1));
+
+        loop {
+            let timestamp: u128 = match self.now() {
+                | Some(timestamp) => timestamp,
+                | None => return false,
+            };
+
+            if timestamp > self.timestamp {
+                self.set_timestamp(timestamp);
+                return true;
+            }
+
+            if started_at.elapsed() >= wait {
+                return false;
+            }
+
+            thread::yield_now();
+        }
+    }
+
+    /// Writes the next ID into the buffer,
+    /// or returns `false` if the iterator has ended.
+    fn advance(&mut self) -> bool {
+        let refreshed: Option<bool> = if self.started {
+            self.refresh_timestamp()
+        } else {
+            self.now().map(|timestamp| {
+                self.set_timestamp(timestamp);
+                true
+            })
+        };
+
+        let refreshed: bool = match refreshed {
+            | Some(refreshed) => refreshed,
+            | None => return false,
+        };
+
+        if !self.monotonic || refreshed {
+            self.fill_randomness();
+        } else if increment_indices(
+            &mut self.indices,
+            self.config.alphabet().len() - 1,
+        )
+        .is_err()
+        {
+            if !self.next_timestamp() {
+                return false;
+            }
+
+            self.fill_randomness();
+        }
+
+        self.started = true;
+        self.buffer.truncate(self.head_length);
+
+        for &index in self.indices.iter() {
+            self.buffer.push(self.config.alphabet().char_at(index));
+        }
+
+        if let Some(check) =
+            self.config.check_symbol(&self.buffer[self.prefix_length..])
+        {
+            self.buffer.push(check);
+        }
+
+        true
+    }
+
+    /// Writes the next ID into `encoded` without allocating a new `String`,
+    /// or returns `false` if the iterator has ended.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     RowIDIter, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let mut iter: RowIDIter = rwc.rowid_iter();
+    /// let mut encoded: String = String::new();
+    ///
+    /// for _ in 0..1_000 {
+    ///     if !iter.next_into(&mut encoded) {
+    ///         break;
+    ///     }
+    ///
+    ///     println!("{}", encoded);
+    /// }
+    /// ```
+    pub fn next_into(
+        &mut self,
+        encoded: &mut String,
+    ) -> bool {
+        if !self.advance() {
+            return false;
+        }
+
+        encoded.clear();
+        encoded.push_str(&self.buffer);
+        true
+    }
+}
+
+impl Iterator for RowIDIter<'_> {
+    type Item = String;
+
+    /// Each ID is cloned from the buffer into a new `String`,
+    /// use `next_into` to reuse a `String` instead.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.advance() { Some(self.buffer.clone()) } else { None }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.monotonic { (0, None) } else { (usize::MAX, None) }
+    }
+}
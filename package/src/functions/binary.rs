@@ -0,0 +1,175 @@
+use crate::common::{alphabet::Alphabet, errors::RowIDError};
+
+pub struct ToBytesOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub length: usize,
+    pub encoded: Encoded,
+}
+
+pub struct FromBytesOptions<'a> {
+    pub alphabet: &'a Alphabet,
+    pub length: usize,
+    pub bytes: &'a [u8],
+}
+
+pub struct ToU128Options<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub length: usize,
+    pub encoded: Encoded,
+}
+
+pub struct FromU128Options<'a> {
+    pub alphabet: &'a Alphabet,
+    pub length: usize,
+    pub value: u128,
+}
+
+fn bits_per_char(alphabet: &Alphabet) -> Result<usize, RowIDError> {
+    let char_list_length: usize = alphabet.len();
+
+    if char_list_length < 2 || !char_list_length.is_power_of_two() {
+        return Err(RowIDError::BinaryCharList { actual: char_list_length });
+    }
+
+    Ok(char_list_length.trailing_zeros() as usize)
+}
+
+fn to_indices(
+    alphabet: &Alphabet,
+    length: usize,
+    encoded: &str,
+) -> Result<Vec<usize>, RowIDError> {
+    let indices: Vec<usize> = encoded
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            alphabet
+                .index_of(character)
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect::<Result<Vec<usize>, RowIDError>>()?;
+
+    if indices.len() != length {
+        return Err(RowIDError::EncodedLength {
+            expected: length,
+            actual: indices.len(),
+        });
+    }
+
+    Ok(indices)
+}
+
+pub fn _to_bytes<Encoded: AsRef<str>>(
+    opts: ToBytesOptions<Encoded>
+) -> Result<Vec<u8>, RowIDError> {
+    let bits: usize = bits_per_char(opts.alphabet)?;
+    let indices: Vec<usize> =
+        to_indices(opts.alphabet, opts.length, opts.encoded.as_ref())?;
+
+    let total_bits: usize = opts.length * bits;
+    let bytes_length: usize = total_bits.div_ceil(8);
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(bytes_length);
+    let mut buffer: u64 = 0;
+    // The ID is right-aligned so that the padding bits are leading zeros.
+    let mut buffer_bits: usize = bytes_length * 8 - total_bits;
+
+    for index in indices {
+        buffer = (buffer << bits) | index as u64;
+        buffer_bits += bits;
+
+        while buffer_bits >= 8 {
+            buffer_bits -= 8;
+            bytes.push((buffer >> buffer_bits) as u8);
+        }
+
+        buffer &= (1 << buffer_bits) - 1;
+    }
+
+    Ok(bytes)
+}
+
+pub fn _from_bytes(opts: FromBytesOptions) -> Result<String, RowIDError> {
+    let bits: usize = bits_per_char(opts.alphabet)?;
+
+    let total_bits: usize = opts.length * bits;
+    let bytes_length: usize = total_bits.div_ceil(8);
+    let padding_bits: usize = bytes_length * 8 - total_bits;
+
+    if opts.bytes.len() != bytes_length {
+        return Err(RowIDError::BinaryLength {
+            expected: total_bits,
+            actual: opts.bytes.len() * 8,
+        });
+    }
+
+    if padding_bits > 0 && opts.bytes[0] >> (8 - padding_bits) != 0 {
+        return Err(RowIDError::BinaryLength {
+            expected: total_bits,
+            actual: bytes_length * 8 - opts.bytes[0].leading_zeros() as usize,
+        });
+    }
+
+    let mut encoded: String = String::with_capacity(opts.length);
+    let mut buffer: u64 = 0;
+    let mut buffer_bits: usize = 0;
+    let mut skip_bits: usize = padding_bits;
+
+    for &byte in opts.bytes {
+        buffer = (buffer << 8) | byte as u64;
+        buffer_bits += 8 - skip_bits;
+        buffer &= (1 << buffer_bits) - 1;
+        skip_bits = 0;
+
+        while buffer_bits >= bits {
+            buffer_bits -= bits;
+            encoded
+                .push(opts.alphabet.char_at((buffer >> buffer_bits) as usize));
+            buffer &= (1 << buffer_bits) - 1;
+        }
+    }
+
+    Ok(encoded)
+}
+
+pub fn _to_u128<Encoded: AsRef<str>>(
+    opts: ToU128Options<Encoded>
+) -> Result<u128, RowIDError> {
+    let bits: usize = bits_per_char(opts.alphabet)?;
+
+    if opts.length * bits > 128 {
+        return Err(RowIDError::BinaryWidth { bits: opts.length * bits });
+    }
+
+    let indices: Vec<usize> =
+        to_indices(opts.alphabet, opts.length, opts.encoded.as_ref())?;
+
+    Ok(indices
+        .into_iter()
+        .fold(0, |value, index| (value << bits) | index as u128))
+}
+
+pub fn _from_u128(opts: FromU128Options) -> Result<String, RowIDError> {
+    let bits: usize = bits_per_char(opts.alphabet)?;
+    let total_bits: usize = opts.length * bits;
+
+    if total_bits > 128 {
+        return Err(RowIDError::BinaryWidth { bits: total_bits });
+    }
+
+    if total_bits < 128 && opts.value >> total_bits != 0 {
+        return Err(RowIDError::BinaryLength {
+            expected: total_bits,
+            actual: 128 - opts.value.leading_zeros() as usize,
+        });
+    }
+
+    let mask: u128 = (1 << bits) - 1;
+
+    Ok((0..opts.length)
+        .rev()
+        .map(|i| {
+            opts.alphabet.char_at(((opts.value >> (i * bits)) & mask) as usize)
+        })
+        .collect())
+}
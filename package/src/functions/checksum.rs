@@ -0,0 +1,171 @@
+use crate::common::{alphabet::Alphabet, errors::RowIDError};
+
+/// The extra check symbols of Crockford's Base32, for the values 32 to 36.
+const CROCKFORD_CHECK_SYMBOLS: &str = "*~$=U";
+
+/// The algorithm of the check symbol appended to the IDs,
+/// a mistyped character is then rejected instead of
+/// being decoded into a wrong timestamp.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{Checksum, RowIDWithConfig, RowIDWithConfigResult};
+///
+/// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+///     .checksum(Checksum::LuhnModN)
+///     .done()
+///     .unwrap();
+///
+/// let id: String = rwc.rowid();
+///
+/// assert!(rwc.try_verify(&id).is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Checksum {
+    /// The Luhn mod N algorithm with a check symbol from the `char_list`,
+    /// it detects every single mistyped character
+    /// and most swaps of adjacent characters.
+    LuhnModN,
+    /// Crockford's mod 37 check symbol,
+    /// it requires a `char_list` of 32 characters,
+    /// and the check symbol may also be one of `*`, `~`, `$`, `=` and `U`.
+    Crockford,
+}
+
+pub struct ChecksumOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub checksum: Checksum,
+    pub encoded: Encoded,
+}
+
+fn check_index<I: DoubleEndedIterator<Item = usize>>(
+    checksum: Checksum,
+    base: usize,
+    indices: I,
+) -> usize {
+    match checksum {
+        | Checksum::LuhnModN => {
+            let sum: usize = indices
+                .rev()
+                .enumerate()
+                .map(|(i, index)| {
+                    let addend: usize =
+                        if i % 2 == 0 { index * 2 } else { index };
+
+                    addend / base + addend % base
+                })
+                .sum();
+
+            (base - sum % base) % base
+        },
+        | Checksum::Crockford => {
+            indices.fold(0, |remainder, index| (remainder * base + index) % 37)
+        },
+    }
+}
+
+fn check_symbol(
+    alphabet: &Alphabet,
+    index: usize,
+) -> char {
+    match index.checked_sub(alphabet.len()) {
+        | Some(i) => CROCKFORD_CHECK_SYMBOLS.as_bytes()[i] as char,
+        | None => alphabet.char_at(index),
+    }
+}
+
+fn check_symbol_index(
+    alphabet: &Alphabet,
+    checksum: Checksum,
+    c: char,
+) -> Option<usize> {
+    alphabet.index_of(c).or_else(|| match checksum {
+        | Checksum::LuhnModN => None,
+        | Checksum::Crockford => CROCKFORD_CHECK_SYMBOLS
+            .find(c.to_ascii_uppercase())
+            .map(|i| alphabet.len() + i),
+    })
+}
+
+/// Returns an error if the check symbols cannot be told apart
+/// from the characters of the alphabet.
+pub fn validate_checksum(
+    alphabet: &Alphabet,
+    checksum: Checksum,
+) -> Result<(), RowIDError> {
+    match checksum {
+        | Checksum::LuhnModN => Ok(()),
+        | Checksum::Crockford => {
+            if alphabet.len() != 32
+                || CROCKFORD_CHECK_SYMBOLS
+                    .chars()
+                    .flat_map(|c| [c, c.to_ascii_lowercase()])
+                    .any(|c| alphabet.index_of(c).is_some())
+            {
+                return Err(RowIDError::ChecksumCharList);
+            }
+
+            Ok(())
+        },
+    }
+}
+
+/// Returns the check symbol of an ID generated from the alphabet.
+pub fn _checksum<Encoded: AsRef<str>>(opts: ChecksumOptions<Encoded>) -> char {
+    let indices: Vec<usize> = opts
+        .encoded
+        .as_ref()
+        .chars()
+        .filter_map(|c| opts.alphabet.index_of(c))
+        .collect();
+
+    check_symbol(
+        opts.alphabet,
+        check_index(opts.checksum, opts.alphabet.len(), indices.into_iter()),
+    )
+}
+
+/// Splits the ID into the part before the check symbol
+/// and the check symbol in its canonical form,
+/// an error is returned if the check symbol does not match.
+pub fn _strip_checksum<'a>(
+    opts: ChecksumOptions<'_, &'a str>
+) -> Result<(&'a str, char), RowIDError> {
+    let encoded: &'a str = opts.encoded;
+
+    let (split, actual): (usize, char) = match encoded.char_indices().last() {
+        | Some(last) => last,
+        | None => {
+            return Err(RowIDError::EncodedLength { expected: 1, actual: 0 });
+        },
+    };
+
+    let body: &'a str = &encoded[..split];
+
+    let indices: Vec<usize> = body
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            opts.alphabet
+                .index_of(character)
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect::<Result<_, _>>()?;
+
+    let expected: usize =
+        check_index(opts.checksum, opts.alphabet.len(), indices.into_iter());
+
+    if check_symbol_index(opts.alphabet, opts.checksum, actual)
+        != Some(expected)
+    {
+        return Err(RowIDError::ChecksumMismatch {
+            expected: check_symbol(opts.alphabet, expected),
+            actual,
+        });
+    }
+
+    Ok((body, check_symbol(opts.alphabet, expected)))
+}
@@ -1,58 +1,58 @@
-use std::{collections::HashMap, io, time::SystemTime};
+use std::time::SystemTime;
 
 use crate::{
-    common::{configs::TIMESTAMP_LENGTH, errors::RowIDError},
-    time::timestamp_to_system_time,
+    common::{alphabet::Alphabet, errors::RowIDError},
+    time::{Precision, timestamp_to_system_time_since},
 };
 
-pub struct DecodeOptions<CharList: AsRef<str>, Encoded: AsRef<str>> {
-    pub char_list: CharList,
+pub struct DecodeOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
     pub encoded: Encoded,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
 }
 
-pub fn _decode<CharList: AsRef<str>, Encoded: AsRef<str>>(
-    opts: DecodeOptions<CharList, Encoded>
-) -> io::Result<SystemTime> {
-    let char_list: &str = opts.char_list.as_ref();
+pub fn _decode<Encoded: AsRef<str>>(
+    opts: DecodeOptions<Encoded>
+) -> Result<SystemTime, RowIDError> {
     let encoded: &str = opts.encoded.as_ref();
 
-    if encoded.len() < TIMESTAMP_LENGTH {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            RowIDError::EncodedLength.as_str(),
-        ));
-    }
-
-    let char_list: Vec<char> = char_list.chars().collect();
-    let char_list_length: usize = char_list.len();
-    let char_index_map: HashMap<char, usize> =
-        char_list.iter().enumerate().map(|(i, &c)| (c, i)).collect();
-
-    let mut encoded_chars: [char; TIMESTAMP_LENGTH] = ['\0'; TIMESTAMP_LENGTH];
-
-    for (i, c) in encoded[..TIMESTAMP_LENGTH].chars().enumerate() {
-        encoded_chars[i] = c;
-    }
+    let encoded_chars: Vec<char> =
+        encoded.chars().take(opts.timestamp_length).collect();
 
-    if !encoded_chars.iter().all(|c| char_index_map.contains_key(c)) {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            RowIDError::InvalidEncoded.as_str(),
-        ));
+    if encoded_chars.len() < opts.timestamp_length {
+        return Err(RowIDError::EncodedLength {
+            expected: opts.timestamp_length,
+            actual: encoded_chars.len(),
+        });
     }
 
-    let mut timestamp: usize = 0;
-
-    for c in encoded_chars {
-        if let Some(index) = char_index_map.get(&c) {
-            timestamp = timestamp * char_list_length + index;
-        } else {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                RowIDError::InvalidEncoded.as_str(),
-            ));
-        }
+    let indices: Vec<usize> = encoded_chars
+        .into_iter()
+        .enumerate()
+        .map(|(position, character)| {
+            opts.alphabet
+                .index_of(character)
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect::<Result<Vec<usize>, RowIDError>>()?;
+
+    let char_list_length: u128 = opts.alphabet.len() as u128;
+    let mut timestamp: u128 = 0;
+
+    for index in indices {
+        timestamp = match timestamp
+            .checked_mul(char_list_length)
+            .and_then(|t| t.checked_add(index as u128))
+        {
+            | Some(t) => t,
+            | None => {
+                return Err(RowIDError::TimestampOverflow { timestamp: None });
+            },
+        };
     }
 
-    Ok(timestamp_to_system_time(timestamp))
+    timestamp_to_system_time_since(timestamp, opts.epoch, opts.precision)
+        .ok_or(RowIDError::TimestampOverflow { timestamp: Some(timestamp) })
 }
@@ -1,50 +1,95 @@
-use std::{
-    io,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::SystemTime;
 
 use crate::{
-    common::{configs::TIMESTAMP_LENGTH, errors::RowIDError},
-    time::system_time_to_timestamp,
+    common::{alphabet::Alphabet, errors::RowIDError},
+    time::{Precision, system_time_to_timestamp_since},
 };
 
-pub struct EncodeOptions<CharList: AsRef<str>> {
-    pub char_list: CharList,
+pub struct EncodeOptions<'a> {
+    pub alphabet: &'a Alphabet,
     pub system_time: SystemTime,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+}
+
+/// Returns the largest timestamp that can be encoded,
+/// or `None` if it is larger than `u128::MAX`.
+pub fn max_timestamp(
+    char_list_length: usize,
+    timestamp_length: usize,
+) -> Option<u128> {
+    let exponent: u32 = u32::try_from(timestamp_length).ok()?;
+
+    (char_list_length as u128).checked_pow(exponent).map(|v| v - 1)
 }
 
-fn _encode_<CharList: AsRef<str>>(opts: EncodeOptions<CharList>) -> String {
-    let char_list: Vec<char> = opts.char_list.as_ref().chars().collect();
-    let char_list_length: usize = char_list.len();
+/// Encodes the value into exactly `length` characters,
+/// the higher digits are dropped if it does not fit.
+pub fn encode_fixed(
+    alphabet: &Alphabet,
+    value: u128,
+    length: usize,
+) -> String {
+    let char_list_length: u128 = alphabet.len() as u128;
 
-    let mut index: usize = TIMESTAMP_LENGTH;
-    let mut encoded: [char; TIMESTAMP_LENGTH] = ['\0'; TIMESTAMP_LENGTH];
-    let mut remaining: usize = system_time_to_timestamp(opts.system_time);
+    let mut index: usize = length;
+    let mut encoded: Vec<char> = vec!['\0'; length];
+    let mut remaining: u128 = value;
 
     while index > 0 {
         index -= 1;
-        encoded[index] = char_list[remaining % char_list_length];
+        encoded[index] =
+            alphabet.char_at((remaining % char_list_length) as usize);
         remaining /= char_list_length;
     }
 
     encoded.iter().collect()
 }
 
-pub fn encode_unsafe<CharList: AsRef<str>>(
-    opts: EncodeOptions<CharList>
-) -> String {
-    _encode_(opts)
+/// Clamps the timestamp to the largest one that can be encoded,
+/// so a timestamp that does not fit never wraps around.
+pub fn saturate_timestamp(
+    alphabet: &Alphabet,
+    timestamp: u128,
+    timestamp_length: usize,
+) -> u128 {
+    match max_timestamp(alphabet.len(), timestamp_length) {
+        | Some(max) => timestamp.min(max),
+        | None => timestamp,
+    }
 }
 
-pub fn _encode<CharList: AsRef<str>>(
-    opts: EncodeOptions<CharList>
-) -> io::Result<String> {
-    if opts.system_time < UNIX_EPOCH {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            RowIDError::InvalidEncoded.as_str(),
-        ));
+/// Returns the timestamp if it can be encoded,
+/// or `TimestampOverflow` otherwise.
+pub fn check_timestamp(
+    alphabet: &Alphabet,
+    timestamp: u128,
+    timestamp_length: usize,
+) -> Result<u128, RowIDError> {
+    if max_timestamp(alphabet.len(), timestamp_length)
+        .is_some_and(|max| timestamp > max)
+    {
+        return Err(RowIDError::TimestampOverflow {
+            timestamp: Some(timestamp),
+        });
     }
 
-    Ok(_encode_(opts))
+    Ok(timestamp)
+}
+
+pub fn _encode(opts: EncodeOptions) -> Result<String, RowIDError> {
+    let timestamp: u128 = match system_time_to_timestamp_since(
+        opts.system_time,
+        opts.epoch,
+        opts.precision,
+    ) {
+        | Some(t) => t,
+        | None => return Err(RowIDError::SystemTimeBeforeEpoch),
+    };
+
+    let timestamp: u128 =
+        check_timestamp(opts.alphabet, timestamp, opts.timestamp_length)?;
+
+    Ok(encode_fixed(opts.alphabet, timestamp, opts.timestamp_length))
 }
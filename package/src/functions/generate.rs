@@ -1,17 +1,30 @@
 use std::{io, time::SystemTime};
 
-use crate::functions::{
-    encode::{_encode, EncodeOptions},
-    get_randomness::{_get_randomness, GetRandomnessOptions},
+use crate::{
+    common::{alphabet::Alphabet, errors::RowIDError, id::RowID},
+    functions::{
+        encode::{_encode, EncodeOptions},
+        get_randomness::{_get_randomness, GetRandomnessOptions},
+    },
+    random::RandomSource,
+    time::{Precision, truncate_system_time},
 };
 
-pub struct GenerateOptions<CharList: AsRef<str>> {
-    pub char_list: CharList,
+pub struct GenerateOptions<'a> {
+    pub alphabet: &'a Alphabet,
     pub system_time: SystemTime,
     pub randomness_length: usize,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+    pub random_source: &'a dyn RandomSource,
 }
 
 /// Result of the `generate` function.
+///
+/// Prefer `try_generate` that returns `Result<String, RowIDError>`,
+/// `From<Result<String, RowIDError>>` is implemented for compatibility,
+/// the error is converted into `io::Error`.
 #[derive(Debug)]
 pub struct GenerateResult {
     /// Tells whether the generation is success or not.
@@ -22,34 +35,40 @@ pub struct GenerateResult {
     pub error: Option<io::Error>,
 }
 
-pub fn _generate<CharList: AsRef<str>>(
-    opts: GenerateOptions<CharList>
-) -> GenerateResult {
-    let char_list: &str = opts.char_list.as_ref();
+impl From<Result<String, RowIDError>> for GenerateResult {
+    fn from(result: Result<String, RowIDError>) -> Self {
+        match result {
+            | Ok(id) => Self { success: true, result: Some(id), error: None },
+            | Err(e) => {
+                Self { success: false, result: None, error: Some(e.into()) }
+            },
+        }
+    }
+}
 
-    let encoded: String = match _encode(EncodeOptions {
-        char_list,
+pub fn _generate_typed(opts: GenerateOptions) -> Result<RowID, RowIDError> {
+    let encoded: String = _encode(EncodeOptions {
+        alphabet: opts.alphabet,
         system_time: opts.system_time,
-    }) {
-        | Ok(res) => res,
-        | Err(e) => {
-            return GenerateResult {
-                success: false,
-                result: None,
-                error: Some(e),
-            };
-        },
-    };
+        epoch: opts.epoch,
+        precision: opts.precision,
+        timestamp_length: opts.timestamp_length,
+    })?;
 
     let extra_randomness_length: String =
         _get_randomness(GetRandomnessOptions {
-            char_list,
+            alphabet: opts.alphabet,
             randomness_length: opts.randomness_length,
+            random_source: opts.random_source,
         });
 
-    GenerateResult {
-        success: true,
-        result: Some(format!("{}{}", encoded, extra_randomness_length)),
-        error: None,
-    }
+    Ok(RowID::from_parts(
+        format!("{}{}", encoded, extra_randomness_length),
+        truncate_system_time(opts.system_time, opts.epoch, opts.precision),
+        opts.timestamp_length,
+    ))
+}
+
+pub fn _generate(opts: GenerateOptions) -> GenerateResult {
+    _generate_typed(opts).map(RowID::into_string).into()
 }
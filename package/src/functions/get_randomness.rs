@@ -1,22 +1,30 @@
-use rand::{Rng, rngs::ThreadRng};
+use crate::{common::alphabet::Alphabet, random::RandomSource};
 
-pub struct GetRandomnessOptions<CharList: AsRef<str>> {
-    pub char_list: CharList,
+pub struct GetRandomnessOptions<'a> {
+    pub alphabet: &'a Alphabet,
     pub randomness_length: usize,
+    pub random_source: &'a dyn RandomSource,
 }
 
-pub fn _get_randomness<CharList: AsRef<str>>(
-    opts: GetRandomnessOptions<CharList>
-) -> String {
-    let char_list: Vec<char> = opts.char_list.as_ref().chars().collect();
-    let length: usize = opts.randomness_length;
-    let mut randomness: Vec<char> = Vec::with_capacity(length);
-    let mut rng: ThreadRng = rand::rng();
+pub fn get_randomness_indices(
+    random_source: &dyn RandomSource,
+    char_list_length: usize,
+    randomness_length: usize,
+) -> Vec<usize> {
+    let mut indices: Vec<usize> = vec![0; randomness_length];
 
-    for _ in 0..length {
-        let index: usize = rng.random_range(0..char_list.len());
-        randomness.push(char_list[index]);
-    }
+    random_source.fill_indices(char_list_length, &mut indices);
 
-    randomness.into_iter().collect()
+    indices
+}
+
+pub fn _get_randomness(opts: GetRandomnessOptions) -> String {
+    get_randomness_indices(
+        opts.random_source,
+        opts.alphabet.len(),
+        opts.randomness_length,
+    )
+    .into_iter()
+    .map(|i| opts.alphabet.char_at(i))
+    .collect()
 }
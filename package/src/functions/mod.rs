@@ -1,6 +1,19 @@
+pub mod batch;
+pub mod binary;
+pub mod checksum;
 pub mod decode;
 pub mod encode;
 pub mod generate;
 pub mod get_randomness;
+pub mod monotonic;
+pub mod node;
+pub mod normalize;
+pub mod parse;
+pub mod prefix;
 pub mod rowid;
+pub mod sequence;
+pub mod shared;
+pub mod ulid;
+#[cfg(feature = "uuid")]
+pub mod uuid;
 pub mod verify;
@@ -0,0 +1,119 @@
+use crate::{
+    common::{errors::RowIDError, id::RowID},
+    functions::get_randomness::get_randomness_indices,
+    with_config::RowIDWithConfigResult,
+};
+
+/// A stateful generator that guarantees strictly increasing IDs.
+///
+/// When an ID is generated within the same millisecond as the previous one,
+/// the randomness of the previous ID is incremented by one
+/// instead of being generated again.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{
+///     MonotonicGenerator, RowIDWithConfig, RowIDWithConfigResult,
+/// };
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().done().unwrap();
+/// let mut generator: MonotonicGenerator = rwc.monotonic();
+///
+/// let first: String = generator.rowid().unwrap();
+/// let second: String = generator.rowid().unwrap();
+///
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonotonicGenerator {
+    config: RowIDWithConfigResult,
+    last_timestamp: Option<u128>,
+    last_randomness: Vec<usize>,
+}
+
+impl MonotonicGenerator {
+    pub(crate) fn new(config: RowIDWithConfigResult) -> Self {
+        Self {
+            last_timestamp: None,
+            last_randomness: Vec::with_capacity(config.state.randomness_length),
+            config,
+        }
+    }
+
+    /// This function generates a unique ID
+    /// that is greater than the previous one.
+    ///
+    /// An error is returned when the randomness overflows
+    /// within the same millisecond,
+    /// or when the time is later than `max_system_time`.
+    pub fn rowid(&mut self) -> Result<String, RowIDError> {
+        self.rowid_typed().map(RowID::into_string)
+    }
+
+    /// This function generates a unique ID as a `RowID`
+    /// that is greater than the previous one.
+    ///
+    /// An error is returned when the randomness overflows
+    /// within the same millisecond,
+    /// or when the time is later than `max_system_time`.
+    pub fn rowid_typed(&mut self) -> Result<RowID, RowIDError> {
+        let timestamp: u128 = self.config.now_timestamp()?;
+
+        let timestamp: u128 = match self.last_timestamp {
+            | Some(last) if timestamp <= last => {
+                self.increment_randomness()?;
+                last
+            },
+            | _ => {
+                self.last_randomness = get_randomness_indices(
+                    self.config.state.random_source.as_ref(),
+                    self.config.alphabet().len(),
+                    self.config.state.randomness_length,
+                );
+                timestamp
+            },
+        };
+
+        self.last_timestamp = Some(timestamp);
+
+        let randomness: String = self
+            .last_randomness
+            .iter()
+            .map(|&i| self.config.alphabet().char_at(i))
+            .collect();
+
+        Ok(self.config.assemble_typed(timestamp, &randomness))
+    }
+
+    fn increment_randomness(&mut self) -> Result<(), RowIDError> {
+        increment_indices(
+            &mut self.last_randomness,
+            self.config.alphabet().len() - 1,
+        )
+    }
+}
+
+/// Increments the indices by one as a number in base `max_index + 1`,
+/// an error is returned if every index is already `max_index`.
+pub fn increment_indices(
+    indices: &mut [usize],
+    max_index: usize,
+) -> Result<(), RowIDError> {
+    let position: usize =
+        match indices.iter().rposition(|&index| index < max_index) {
+            | Some(p) => p,
+            | None => {
+                return Err(RowIDError::RandomnessOverflow);
+            },
+        };
+
+    indices[position] += 1;
+
+    for index in indices[position + 1..].iter_mut() {
+        *index = 0;
+    }
+
+    Ok(())
+}
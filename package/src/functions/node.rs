@@ -0,0 +1,129 @@
+use std::{env, str::Chars, time::SystemTime};
+
+use crate::{
+    common::{alphabet::Alphabet, errors::RowIDError},
+    functions::{
+        decode::{_decode, DecodeOptions},
+        encode::{encode_fixed, max_timestamp},
+    },
+    time::Precision,
+};
+
+pub struct EncodeNodeOptions<'a> {
+    pub alphabet: &'a Alphabet,
+    pub node_id: u64,
+    pub node_length: usize,
+}
+
+pub struct DecodePartsOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub encoded: Encoded,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+    pub node_length: Option<usize>,
+}
+
+/// The parts of an ID decoded separately.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{
+///     DecodedParts, RowIDWithConfig, RowIDWithConfigResult,
+/// };
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().node_id(42).done().unwrap();
+/// let parts: DecodedParts = rwc.decode_parts(rwc.rowid()).unwrap();
+///
+/// assert!(parts.node_id == Some(42));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedParts {
+    /// The decoded timestamp part of the ID.
+    pub timestamp: SystemTime,
+    /// The decoded node ID,
+    /// or `None` if the configuration has no node ID.
+    pub node_id: Option<u64>,
+    /// The randomness part of the ID, without the check symbol.
+    pub randomness: String,
+}
+
+/// Encodes the node ID into exactly `node_length` characters,
+/// an error is returned if it does not fit.
+pub fn _encode_node(opts: EncodeNodeOptions) -> Result<String, RowIDError> {
+    if max_timestamp(opts.alphabet.len(), opts.node_length)
+        .is_some_and(|max| opts.node_id as u128 > max)
+    {
+        return Err(RowIDError::NodeIDOverflow { node_id: opts.node_id });
+    }
+
+    Ok(encode_fixed(opts.alphabet, opts.node_id as u128, opts.node_length))
+}
+
+pub fn _decode_parts<Encoded: AsRef<str>>(
+    opts: DecodePartsOptions<Encoded>
+) -> Result<DecodedParts, RowIDError> {
+    let encoded: &str = opts.encoded.as_ref();
+
+    let timestamp: SystemTime = _decode(DecodeOptions {
+        alphabet: opts.alphabet,
+        encoded,
+        epoch: opts.epoch,
+        precision: opts.precision,
+        timestamp_length: opts.timestamp_length,
+    })?;
+
+    let node_length: usize = opts.node_length.unwrap_or(0);
+
+    let split: usize = encoded
+        .char_indices()
+        .nth(opts.timestamp_length)
+        .map_or(encoded.len(), |(i, _)| i);
+
+    let mut rest: Chars = encoded[split..].chars();
+
+    let node: Vec<char> = rest.by_ref().take(node_length).collect();
+
+    if node.len() < node_length {
+        return Err(RowIDError::EncodedLength {
+            expected: opts.timestamp_length + node_length,
+            actual: opts.timestamp_length + node.len(),
+        });
+    }
+
+    let char_list_length: u64 = opts.alphabet.len() as u64;
+
+    let node_id: u64 = node.into_iter().enumerate().try_fold(
+        0u64,
+        |value, (position, character)| {
+            let index: usize = opts.alphabet.index_of(character).ok_or(
+                RowIDError::InvalidEncoded {
+                    character,
+                    position: opts.timestamp_length + position,
+                },
+            )?;
+
+            value
+                .checked_mul(char_list_length)
+                .and_then(|v| v.checked_add(index as u64))
+                .ok_or(RowIDError::NodeIDOverflow { node_id: value })
+        },
+    )?;
+
+    Ok(DecodedParts {
+        timestamp,
+        node_id: opts.node_length.map(|_| node_id),
+        randomness: rest.collect(),
+    })
+}
+
+/// Reads the node ID from the environment variable,
+/// an error is returned if it is missing or not a valid integer.
+pub fn node_id_from_env(name: &str) -> Result<u64, RowIDError> {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or(RowIDError::NodeIDEnv)
+}
@@ -0,0 +1,22 @@
+use crate::common::{alphabet::Alphabet, errors::RowIDError};
+
+pub struct NormalizeOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub encoded: Encoded,
+}
+
+pub fn _normalize<Encoded: AsRef<str>>(
+    opts: NormalizeOptions<Encoded>
+) -> Result<String, RowIDError> {
+    opts.encoded
+        .as_ref()
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            opts.alphabet
+                .index_of(character)
+                .map(|i| opts.alphabet.char_at(i))
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect()
+}
@@ -0,0 +1,54 @@
+use std::time::SystemTime;
+
+use crate::{
+    common::{alphabet::Alphabet, errors::RowIDError, id::RowID},
+    functions::decode::{_decode, DecodeOptions},
+    time::Precision,
+};
+
+pub struct ParseOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
+    pub encoded: Encoded,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+    /// The number of characters of the whole ID.
+    pub length: usize,
+}
+
+pub fn _parse<Encoded: AsRef<str>>(
+    opts: ParseOptions<Encoded>
+) -> Result<RowID, RowIDError> {
+    let encoded: &str = opts.encoded.as_ref();
+
+    let timestamp: SystemTime = _decode(DecodeOptions {
+        alphabet: opts.alphabet,
+        encoded,
+        epoch: opts.epoch,
+        precision: opts.precision,
+        timestamp_length: opts.timestamp_length,
+    })?;
+
+    // Aliases are replaced so that equal IDs have the same string form.
+    let value: String = encoded
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            opts.alphabet
+                .index_of(character)
+                .map(|i| opts.alphabet.char_at(i))
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect::<Result<String, RowIDError>>()?;
+
+    let length: usize = value.chars().count();
+
+    if length != opts.length {
+        return Err(RowIDError::EncodedLength {
+            expected: opts.length,
+            actual: length,
+        });
+    }
+
+    Ok(RowID::from_parts(value, timestamp, opts.timestamp_length))
+}
@@ -0,0 +1,24 @@
+use crate::common::errors::RowIDError;
+
+pub struct PrefixOptions<'a, Encoded: AsRef<str>> {
+    pub prefix: &'a str,
+    pub encoded: Encoded,
+}
+
+/// Returns the ID without the prefix,
+/// an error is returned if the ID does not start with the prefix.
+pub fn _strip_prefix<'a>(
+    opts: PrefixOptions<'_, &'a str>
+) -> Result<&'a str, RowIDError> {
+    match opts.encoded.strip_prefix(opts.prefix) {
+        | Some(rest) => Ok(rest),
+        | None => Err(RowIDError::PrefixMismatch {
+            position: opts
+                .encoded
+                .chars()
+                .zip(opts.prefix.chars())
+                .take_while(|(a, b)| a == b)
+                .count(),
+        }),
+    }
+}
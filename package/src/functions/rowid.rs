@@ -1,21 +1,58 @@
 use std::time::SystemTime;
 
-use crate::functions::{
-    encode::{EncodeOptions, encode_unsafe},
-    get_randomness::{_get_randomness, GetRandomnessOptions},
+use crate::{
+    common::{alphabet::Alphabet, id::RowID},
+    functions::{
+        encode::{encode_fixed, saturate_timestamp},
+        get_randomness::{_get_randomness, GetRandomnessOptions},
+    },
+    random::RandomSource,
+    time::{
+        Clock, Precision, system_time_to_timestamp_since,
+        timestamp_to_system_time_since,
+    },
 };
 
-pub struct RowIDOptions<CharList: AsRef<str>> {
-    pub char_list: CharList,
+pub struct RowIDOptions<'a> {
+    pub alphabet: &'a Alphabet,
     pub randomness_length: usize,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+    pub clock: &'a dyn Clock,
+    pub random_source: &'a dyn RandomSource,
 }
 
-pub fn _rowid<CharList: AsRef<str>>(opts: RowIDOptions<CharList>) -> String {
-    let char_list: &str = opts.char_list.as_ref();
+pub fn _rowid(opts: RowIDOptions) -> String {
+    _rowid_typed(opts).into_string()
+}
+
+/// The timestamp saturates at the largest one that can be encoded,
+/// see `max_system_time`.
+pub fn _rowid_typed(opts: RowIDOptions) -> RowID {
+    let timestamp: u128 = saturate_timestamp(
+        opts.alphabet,
+        system_time_to_timestamp_since(
+            opts.clock.now(),
+            opts.epoch,
+            opts.precision,
+        )
+        .unwrap_or(0),
+        opts.timestamp_length,
+    );
+
+    let id: String =
+        encode_fixed(opts.alphabet, timestamp, opts.timestamp_length)
+            + &_get_randomness(GetRandomnessOptions {
+                alphabet: opts.alphabet,
+                randomness_length: opts.randomness_length,
+                random_source: opts.random_source,
+            });
 
-    encode_unsafe(EncodeOptions { char_list, system_time: SystemTime::now() })
-        + &_get_randomness(GetRandomnessOptions {
-            char_list,
-            randomness_length: opts.randomness_length,
-        })
+    RowID::from_parts(
+        id,
+        timestamp_to_system_time_since(timestamp, opts.epoch, opts.precision)
+            .unwrap_or(opts.epoch),
+        opts.timestamp_length,
+    )
 }
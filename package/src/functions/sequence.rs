@@ -0,0 +1,149 @@
+use std::{
+    sync::{Mutex, MutexGuard, PoisonError},
+    thread,
+};
+
+use crate::{
+    common::{errors::RowIDError, id::RowID},
+    functions::encode::{encode_fixed, max_timestamp},
+    with_config::RowIDWithConfigResult,
+};
+
+/// What a `SequenceGenerator` does
+/// when the sequence is exhausted within the same timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SequenceExhausted {
+    /// Waits for the clock to reach the next timestamp.
+    #[default]
+    Wait,
+    /// Returns `RowIDError::SequenceOverflow`.
+    Error,
+}
+
+#[derive(Debug)]
+struct SequenceState {
+    last_timestamp: Option<u128>,
+    sequence: u128,
+}
+
+/// A thread-safe generator of IDs made of the timestamp,
+/// the node ID if any, and a sequence that counts up
+/// within the same timestamp instead of randomness.
+///
+/// The IDs are strictly increasing and never collide
+/// as long as each node has its own node ID.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{
+///     RowIDWithConfig, RowIDWithConfigResult, SequenceGenerator,
+/// };
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().node_id(7).done().unwrap();
+/// let generator: SequenceGenerator = rwc.sequence();
+///
+/// let first: String = generator.rowid().unwrap();
+/// let second: String = generator.rowid().unwrap();
+///
+/// assert!(first < second);
+/// ```
+#[derive(Debug)]
+pub struct SequenceGenerator {
+    config: RowIDWithConfigResult,
+    max_sequence: u128,
+    state: Mutex<SequenceState>,
+}
+
+impl SequenceGenerator {
+    /// The sequence takes the place of the randomness in the configuration,
+    /// so the configuration parses the IDs of the generator.
+    pub(crate) fn new(mut config: RowIDWithConfigResult) -> Self {
+        config.state.randomness_length = config.state.sequence_length;
+
+        Self {
+            max_sequence: max_timestamp(
+                config.alphabet().len(),
+                config.state.sequence_length,
+            )
+            .unwrap_or(u128::MAX),
+            config,
+            state: Mutex::new(SequenceState {
+                last_timestamp: None,
+                sequence: 0,
+            }),
+        }
+    }
+
+    /// This function parses an ID generated by the generator into a `RowID`,
+    /// with the same rules as `RowIDWithConfigResult::parse`.
+    pub fn parse<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<RowID, RowIDError> {
+        self.config.parse(encoded)
+    }
+
+    /// This function generates a unique ID
+    /// that is greater than the previous one.
+    ///
+    /// When the sequence is exhausted within the same timestamp,
+    /// it either waits for the next timestamp or returns an error,
+    /// depending on `SequenceExhausted`.
+    pub fn rowid(&self) -> Result<String, RowIDError> {
+        self.rowid_typed().map(RowID::into_string)
+    }
+
+    /// This function generates a unique ID as a `RowID`
+    /// that is greater than the previous one.
+    ///
+    /// When the sequence is exhausted within the same timestamp,
+    /// it either waits for the next timestamp or returns an error,
+    /// depending on `SequenceExhausted`.
+    pub fn rowid_typed(&self) -> Result<RowID, RowIDError> {
+        let (timestamp, sequence): (u128, u128) = self.next()?;
+
+        let sequence: String = encode_fixed(
+            self.config.alphabet(),
+            sequence,
+            self.config.state.sequence_length,
+        );
+
+        Ok(self.config.assemble_typed(timestamp, &sequence))
+    }
+
+    /// Returns the timestamp and the sequence of the next ID,
+    /// the lock is held until both are reserved.
+    fn next(&self) -> Result<(u128, u128), RowIDError> {
+        let mut state: MutexGuard<SequenceState> =
+            self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        loop {
+            let timestamp: u128 = self.config.now_timestamp()?;
+
+            match state.last_timestamp {
+                | Some(last) if timestamp <= last => {
+                    if state.sequence < self.max_sequence {
+                        state.sequence += 1;
+                        return Ok((last, state.sequence));
+                    }
+
+                    match self.config.state.sequence_exhausted {
+                        | SequenceExhausted::Wait => thread::yield_now(),
+                        | SequenceExhausted::Error => {
+                            return Err(RowIDError::SequenceOverflow);
+                        },
+                    }
+                },
+                | _ => {
+                    state.last_timestamp = Some(timestamp);
+                    state.sequence = 0;
+                    return Ok((timestamp, 0));
+                },
+            }
+        }
+    }
+}
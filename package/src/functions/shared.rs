@@ -0,0 +1,245 @@
+#[cfg(loom)]
+use loom::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+#[cfg(not(loom))]
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    thread,
+};
+
+use crate::{
+    common::{errors::RowIDError, id::RowID},
+    functions::{
+        encode::{encode_fixed, max_timestamp},
+        get_randomness::{_get_randomness, GetRandomnessOptions},
+        sequence::SequenceExhausted,
+    },
+    time::system_time_to_timestamp_since,
+    with_config::RowIDWithConfigResult,
+};
+
+/// Returns the largest sequence length
+/// whose counter leaves at least one bit for the timestamp.
+pub fn max_sequence_length(char_list_length: usize) -> usize {
+    let mut length: usize = 0;
+
+    while max_timestamp(char_list_length, length + 1)
+        .is_some_and(|max| max >> (u64::BITS - 1) == 0)
+    {
+        length += 1;
+    }
+
+    length
+}
+
+/// A lock-free generator that guarantees strictly increasing IDs
+/// across every thread that shares it.
+///
+/// The last timestamp and the counter are packed into one `AtomicU64`,
+/// and each ID reserves the next value with a compare-and-swap,
+/// so no two IDs get the same timestamp and counter.
+///
+/// The timestamp is packed relative to the time the generator is created,
+/// the bits left by the counter cover about 17 years
+/// with `Precision::Microseconds` and a 3-character sequence,
+/// after that `rowid` returns `TimestampOverflow`.
+///
+/// The ID is made of the timestamp, the node ID if any,
+/// the counter in `sequence_length` characters,
+/// and the randomness in `randomness_length` characters.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::{sync::Arc, thread};
+/// use rowid::with_config::{
+///     RowIDWithConfig, RowIDWithConfigResult, SharedGenerator,
+/// };
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().randomness_length(8).done().unwrap();
+/// let generator: Arc<SharedGenerator> = Arc::new(rwc.shared().unwrap());
+///
+/// let handle: thread::JoinHandle<String> = {
+///     let generator: Arc<SharedGenerator> = generator.clone();
+///     thread::spawn(move || generator.rowid().unwrap())
+/// };
+///
+/// let id: String = generator.rowid().unwrap();
+/// assert!(id != handle.join().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct SharedGenerator {
+    config: RowIDWithConfigResult,
+    randomness_length: usize,
+    max_counter: u64,
+    counter_bits: u32,
+    start: u128,
+    last: AtomicU64,
+}
+
+impl SharedGenerator {
+    /// The counter and the randomness take the place of the randomness
+    /// in the configuration, so the configuration parses the IDs
+    /// of the generator.
+    pub(crate) fn new(
+        mut config: RowIDWithConfigResult
+    ) -> Result<Self, RowIDError> {
+        let sequence_length: usize = config.state.sequence_length;
+        let max_length: usize = max_sequence_length(config.alphabet().len());
+
+        if sequence_length > max_length {
+            return Err(RowIDError::SequenceLength {
+                max: max_length,
+                actual: sequence_length,
+            });
+        }
+
+        let randomness_length: usize = config.state.randomness_length;
+        config.state.randomness_length = sequence_length + randomness_length;
+
+        let max_counter: u128 =
+            max_timestamp(config.alphabet().len(), sequence_length)
+                .unwrap_or(u128::MAX);
+
+        let counter_bits: u32 = u128::BITS - max_counter.leading_zeros();
+
+        let start: u128 = match system_time_to_timestamp_since(
+            config.state.clock.now(),
+            config.state.epoch,
+            config.state.precision,
+        ) {
+            | Some(start) => start,
+            | None => return Err(RowIDError::SystemTimeBeforeEpoch),
+        };
+
+        Ok(Self {
+            config,
+            randomness_length,
+            max_counter: u64::try_from(max_counter).unwrap_or(u64::MAX),
+            counter_bits,
+            start,
+            last: AtomicU64::new(0),
+        })
+    }
+
+    /// This function parses an ID generated by the generator into a `RowID`,
+    /// with the same rules as `RowIDWithConfigResult::parse`.
+    pub fn parse<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<RowID, RowIDError> {
+        self.config.parse(encoded)
+    }
+
+    /// This function generates a unique ID
+    /// that is greater than every ID generated before it.
+    ///
+    /// When the counter is exhausted within the same timestamp,
+    /// it either waits for the next timestamp or returns an error,
+    /// depending on `SequenceExhausted`.
+    pub fn rowid(&self) -> Result<String, RowIDError> {
+        self.rowid_typed().map(RowID::into_string)
+    }
+
+    /// This function generates a unique ID as a `RowID`
+    /// that is greater than every ID generated before it.
+    ///
+    /// When the counter is exhausted within the same timestamp,
+    /// it either waits for the next timestamp or returns an error,
+    /// depending on `SequenceExhausted`.
+    pub fn rowid_typed(&self) -> Result<RowID, RowIDError> {
+        let (timestamp, counter): (u128, u64) = self.next()?;
+
+        let body: String = encode_fixed(
+            self.config.alphabet(),
+            counter as u128,
+            self.config.state.sequence_length,
+        ) + &_get_randomness(GetRandomnessOptions {
+            alphabet: self.config.alphabet(),
+            randomness_length: self.randomness_length,
+            random_source: self.config.state.random_source.as_ref(),
+        });
+
+        Ok(self.config.assemble_typed(timestamp, &body))
+    }
+
+    /// Reserves the timestamp and the counter of the next ID,
+    /// the packed value only ever increases.
+    fn next(&self) -> Result<(u128, u64), RowIDError> {
+        loop {
+            let timestamp: u128 = self.config.now_timestamp()?;
+
+            let packed: u64 = match self.pack(timestamp) {
+                | Some(packed) => packed,
+                | None => {
+                    return Err(RowIDError::TimestampOverflow {
+                        timestamp: Some(timestamp),
+                    });
+                },
+            };
+
+            let counter_mask: u64 = (1 << self.counter_bits) - 1;
+            let last: u64 = self.last.load(Ordering::Acquire);
+
+            let next: u64 = if packed > last {
+                packed
+            } else if last & counter_mask < self.max_counter {
+                last + 1
+            } else {
+                match self.config.state.sequence_exhausted {
+                    | SequenceExhausted::Wait => {
+                        thread::yield_now();
+                        continue;
+                    },
+                    | SequenceExhausted::Error => {
+                        return Err(RowIDError::SequenceOverflow);
+                    },
+                }
+            };
+
+            if self
+                .last
+                .compare_exchange_weak(
+                    last,
+                    next,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                )
+                .is_ok()
+            {
+                return Ok((
+                    self.unpack(next >> self.counter_bits),
+                    next & counter_mask,
+                ));
+            }
+        }
+    }
+
+    /// Packs the timestamp since the start above the counter bits,
+    /// or returns `None` if it does not fit in 64 bits.
+    ///
+    /// The start is packed as 1,
+    /// so the first ID is always greater than the initial value.
+    fn pack(
+        &self,
+        timestamp: u128,
+    ) -> Option<u64> {
+        u64::try_from(timestamp.saturating_sub(self.start) + 1)
+            .ok()
+            .filter(|&t| {
+                t.checked_shr(u64::BITS - self.counter_bits).unwrap_or(0) == 0
+            })
+            .map(|t| t << self.counter_bits)
+    }
+
+    /// Converts the packed timestamp back into the timestamp.
+    fn unpack(
+        &self,
+        packed: u64,
+    ) -> u128 {
+        self.start + packed as u128 - 1
+    }
+}
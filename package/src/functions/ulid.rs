@@ -0,0 +1,79 @@
+use crate::common::{
+    configs::{
+        ALPHABET, TIMESTAMP_LENGTH, ULID_RANDOMNESS_LENGTH, ULID_TIMESTAMP_BITS,
+    },
+    errors::RowIDError,
+};
+
+const RANDOMNESS_BITS: usize = ULID_RANDOMNESS_LENGTH * 5;
+
+pub struct ToUlidOptions<Encoded: AsRef<str>> {
+    pub encoded: Encoded,
+}
+
+pub struct FromUlidOptions {
+    pub value: u128,
+}
+
+/// Decodes the characters into a number,
+/// the other case of the characters is accepted.
+fn decode_part(
+    encoded: &str,
+    offset: usize,
+) -> Result<u128, RowIDError> {
+    encoded.chars().enumerate().try_fold(0, |value, (position, character)| {
+        ALPHABET
+            .index_of(character.to_ascii_uppercase())
+            .map(|index| (value << 5) | index as u128)
+            .ok_or(RowIDError::InvalidEncoded {
+                character,
+                position: offset + position,
+            })
+    })
+}
+
+pub fn _ulid_to_u128<Encoded: AsRef<str>>(
+    opts: ToUlidOptions<Encoded>
+) -> Result<u128, RowIDError> {
+    let encoded: &str = opts.encoded.as_ref();
+    let length: usize = encoded.chars().count();
+
+    if length != TIMESTAMP_LENGTH + ULID_RANDOMNESS_LENGTH {
+        return Err(RowIDError::EncodedLength {
+            expected: TIMESTAMP_LENGTH + ULID_RANDOMNESS_LENGTH,
+            actual: length,
+        });
+    }
+
+    let split: usize = encoded
+        .char_indices()
+        .nth(TIMESTAMP_LENGTH)
+        .map_or(encoded.len(), |(i, _)| i);
+
+    let timestamp: u128 = decode_part(&encoded[..split], 0)?;
+    let randomness: u128 = decode_part(&encoded[split..], TIMESTAMP_LENGTH)?;
+
+    if timestamp >> ULID_TIMESTAMP_BITS != 0 {
+        return Err(RowIDError::TimestampOverflow {
+            timestamp: Some(timestamp),
+        });
+    }
+
+    Ok((timestamp << RANDOMNESS_BITS) | randomness)
+}
+
+pub fn _u128_to_ulid(opts: FromUlidOptions) -> String {
+    let length: usize = TIMESTAMP_LENGTH + ULID_RANDOMNESS_LENGTH;
+
+    (0..length)
+        .map(|i| {
+            let shift: usize = (length - 1 - i) * 5;
+            ALPHABET.char_at(((opts.value >> shift) & 0x1f) as usize)
+        })
+        .collect()
+}
+
+/// Returns the timestamp in milliseconds of the ULID.
+pub fn ulid_timestamp(value: u128) -> u64 {
+    (value >> RANDOMNESS_BITS) as u64
+}
@@ -0,0 +1,99 @@
+use crate::common::{
+    configs::{
+        ALPHABET, RANDOMNESS_LENGTH, TIMESTAMP_LENGTH, ULID_TIMESTAMP_BITS,
+        UUID_RANDOMNESS_LENGTH,
+    },
+    errors::RowIDError,
+};
+
+const RANDOMNESS_BITS: usize = UUID_RANDOMNESS_LENGTH * 5;
+// The randomness is split into the 12 bits of `rand_a`
+// and the first 58 bits of `rand_b`.
+const RAND_B_BITS: usize = RANDOMNESS_BITS - 12;
+
+pub struct ToUuidOptions<Encoded: AsRef<str>> {
+    pub encoded: Encoded,
+}
+
+pub struct FromUuidOptions {
+    pub value: u128,
+}
+
+pub fn _to_uuid_v7<Encoded: AsRef<str>>(
+    opts: ToUuidOptions<Encoded>
+) -> Result<u128, RowIDError> {
+    // Every character is validated, even those that do not fit.
+    let mut indices: Vec<u128> = opts
+        .encoded
+        .as_ref()
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            ALPHABET
+                .index_of(character)
+                .map(|index| index as u128)
+                .ok_or(RowIDError::InvalidEncoded { character, position })
+        })
+        .collect::<Result<_, _>>()?;
+
+    if indices.len() < TIMESTAMP_LENGTH {
+        return Err(RowIDError::EncodedLength {
+            expected: TIMESTAMP_LENGTH,
+            actual: indices.len(),
+        });
+    }
+
+    if indices.len() > TIMESTAMP_LENGTH + RANDOMNESS_LENGTH {
+        return Err(RowIDError::EncodedLength {
+            expected: TIMESTAMP_LENGTH + RANDOMNESS_LENGTH,
+            actual: indices.len(),
+        });
+    }
+
+    indices.truncate(TIMESTAMP_LENGTH + UUID_RANDOMNESS_LENGTH);
+
+    let (timestamp, randomness): (&[u128], &[u128]) =
+        indices.split_at(TIMESTAMP_LENGTH);
+
+    let timestamp: u128 = timestamp.iter().fold(0, |v, &i| (v << 5) | i);
+
+    if timestamp >> ULID_TIMESTAMP_BITS != 0 {
+        return Err(RowIDError::TimestampOverflow {
+            timestamp: Some(timestamp),
+        });
+    }
+
+    // A shorter randomness is padded with zeros.
+    let randomness: u128 = randomness.iter().fold(0, |v, &i| (v << 5) | i)
+        << ((UUID_RANDOMNESS_LENGTH - randomness.len()) * 5);
+
+    Ok((timestamp << 80)
+        | (0x7 << 76)
+        | ((randomness >> RAND_B_BITS) << 64)
+        | (0b10 << 62)
+        | ((randomness & ((1 << RAND_B_BITS) - 1)) << (62 - RAND_B_BITS)))
+}
+
+pub fn _from_uuid_v7(opts: FromUuidOptions) -> Result<String, RowIDError> {
+    let version: usize = ((opts.value >> 76) & 0xf) as usize;
+
+    if version != 7 {
+        return Err(RowIDError::UuidVersion { version });
+    }
+
+    let timestamp: u128 = opts.value >> 80;
+    let rand_a: u128 = (opts.value >> 64) & 0xfff;
+    let rand_b: u128 = opts.value & ((1 << 62) - 1);
+    let value: u128 = (timestamp << RANDOMNESS_BITS)
+        | (rand_a << RAND_B_BITS)
+        | (rand_b >> (62 - RAND_B_BITS));
+
+    let length: usize = TIMESTAMP_LENGTH + UUID_RANDOMNESS_LENGTH;
+
+    Ok((0..length)
+        .map(|i| {
+            let shift: usize = (length - 1 - i) * 5;
+            ALPHABET.char_at(((value >> shift) & 0x1f) as usize)
+        })
+        .collect())
+}
@@ -1,16 +1,122 @@
-use std::{io, time::SystemTime};
+use std::{
+    io,
+    time::{Duration, SystemTime},
+};
 
-use crate::functions::decode::{_decode, DecodeOptions};
+use crate::{
+    common::{alphabet::Alphabet, errors::RowIDError},
+    functions::decode::{_decode, DecodeOptions},
+    time::{Clock, Precision},
+};
 
-pub struct VerifyOptions<CharList: AsRef<str>, Encoded: AsRef<str>>
-where
-    CharList: AsRef<str>,
-{
-    pub char_list: CharList,
+pub struct VerifyOptions<'a, Encoded: AsRef<str>> {
+    pub alphabet: &'a Alphabet,
     pub encoded: Encoded,
+    pub epoch: SystemTime,
+    pub precision: Precision,
+    pub timestamp_length: usize,
+    pub randomness_length: usize,
+    pub clock: &'a dyn Clock,
+    pub policy: &'a VerifyPolicy,
+}
+
+/// Rules that an ID must follow to pass the verification,
+/// no rule is applied by default.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use rowid::with_config::VerifyPolicy;
+///
+/// let policy: VerifyPolicy = VerifyPolicy::new()
+///     .max_future_skew(Duration::from_secs(5))
+///     .max_age(Duration::from_secs(86_400))
+///     .exact_length(true)
+///     .check_randomness(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerifyPolicy {
+    max_future_skew: Option<Duration>,
+    min_age: Option<Duration>,
+    max_age: Option<Duration>,
+    exact_length: bool,
+    check_randomness: bool,
+}
+
+impl VerifyPolicy {
+    /// Creates a new `VerifyPolicy` without any rule.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects IDs whose time is later than the current time
+    /// by more than the given duration,
+    /// `Duration::ZERO` rejects every ID from the future.
+    ///
+    /// default: `None`
+    pub fn max_future_skew(
+        mut self,
+        skew: Duration,
+    ) -> Self {
+        self.max_future_skew = Some(skew);
+        self
+    }
+
+    /// Rejects IDs that are younger than the given duration,
+    /// IDs from the future have an age of zero.
+    ///
+    /// default: `None`
+    pub fn min_age(
+        mut self,
+        age: Duration,
+    ) -> Self {
+        self.min_age = Some(age);
+        self
+    }
+
+    /// Rejects IDs that are older than the given duration.
+    ///
+    /// default: `None`
+    pub fn max_age(
+        mut self,
+        age: Duration,
+    ) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Requires the length of the ID to be exactly
+    /// the timestamp length plus the randomness length.
+    ///
+    /// default: `false`
+    pub fn exact_length(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.exact_length = enabled;
+        self
+    }
+
+    /// Requires every character in the randomness
+    /// to be in the `char_list`.
+    ///
+    /// default: `false`
+    pub fn check_randomness(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.check_randomness = enabled;
+        self
+    }
 }
 
 /// Result of the `verify` function.
+///
+/// Prefer `try_verify` that returns `Result<Verified, RowIDError>`,
+/// `From<Result<Verified, RowIDError>>` is implemented for compatibility,
+/// the error is converted into `io::Error`.
 #[derive(Debug)]
 pub struct VerifyResult {
     /// Tells whether the verification is success or not.
@@ -23,28 +129,97 @@ pub struct VerifyResult {
     pub error: Option<io::Error>,
 }
 
-pub fn _verify<CharList: AsRef<str>, Encoded: AsRef<str>>(
-    opts: VerifyOptions<CharList, Encoded>
-) -> VerifyResult {
-    let result: SystemTime = match _decode(DecodeOptions {
-        char_list: opts.char_list,
-        encoded: opts.encoded,
-    }) {
-        | Ok(res) => res,
-        | Err(e) => {
-            return VerifyResult {
+/// Result of the `try_verify` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verified {
+    /// Decoded system time based on the ID.
+    pub time: SystemTime,
+    /// Tells whether the ID is natural or not,
+    /// an ID is natural if its time is before the current time.
+    pub natural: bool,
+}
+
+impl From<Result<Verified, RowIDError>> for VerifyResult {
+    fn from(result: Result<Verified, RowIDError>) -> Self {
+        match result {
+            | Ok(verified) => Self {
+                success: true,
+                result: Some(verified.time),
+                natural: Some(verified.natural),
+                error: None,
+            },
+            | Err(e) => Self {
                 success: false,
                 result: None,
                 natural: None,
-                error: Some(e),
-            };
+                error: Some(e.into()),
+            },
+        }
+    }
+}
+
+pub fn _try_verify<Encoded: AsRef<str>>(
+    opts: VerifyOptions<Encoded>
+) -> Result<Verified, RowIDError> {
+    let encoded: &str = opts.encoded.as_ref();
+
+    if opts.policy.exact_length {
+        let expected: usize = opts.timestamp_length + opts.randomness_length;
+        let actual: usize = encoded.chars().count();
+
+        if actual != expected {
+            return Err(RowIDError::EncodedLength { expected, actual });
+        }
+    }
+
+    let time: SystemTime = _decode(DecodeOptions {
+        alphabet: opts.alphabet,
+        encoded,
+        epoch: opts.epoch,
+        precision: opts.precision,
+        timestamp_length: opts.timestamp_length,
+    })?;
+
+    if opts.policy.check_randomness {
+        if let Some((position, character)) = encoded
+            .chars()
+            .enumerate()
+            .skip(opts.timestamp_length)
+            .find(|(_, c)| opts.alphabet.index_of(*c).is_none())
+        {
+            return Err(RowIDError::InvalidEncoded { character, position });
+        }
+    }
+
+    let now: SystemTime = opts.clock.now();
+
+    // IDs from the future have an age of zero.
+    let age: Duration = match now.duration_since(time) {
+        | Ok(age) => age,
+        | Err(e) => {
+            let ahead: Duration = e.duration();
+
+            if opts.policy.max_future_skew.is_some_and(|skew| ahead > skew) {
+                return Err(RowIDError::TimestampInFuture { ahead });
+            }
+
+            Duration::ZERO
         },
     };
 
-    VerifyResult {
-        success: true,
-        result: Some(result),
-        natural: Some(result < SystemTime::now()),
-        error: None,
+    if opts.policy.min_age.is_some_and(|min| age < min) {
+        return Err(RowIDError::TimestampTooNew { age });
+    }
+
+    if opts.policy.max_age.is_some_and(|max| age > max) {
+        return Err(RowIDError::TimestampTooOld { age });
     }
+
+    Ok(Verified { time, natural: time < now })
+}
+
+pub fn _verify<Encoded: AsRef<str>>(
+    opts: VerifyOptions<Encoded>
+) -> VerifyResult {
+    _try_verify(opts).into()
 }
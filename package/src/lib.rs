@@ -28,6 +28,7 @@
 
 mod common;
 mod functions;
+mod macros;
 
 /// Base module that contains the basic functions and structs.
 pub mod base;
@@ -37,3 +38,24 @@ pub mod with_config;
 
 /// Time module.
 pub mod time;
+
+/// Random module.
+pub mod random;
+
+/// ULID module, for interoperability with ULIDs.
+pub mod ulid;
+
+/// UUID module, for interoperability with version 7 UUIDs.
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "serde")]
+    pub use crate::common::serde_impls::{
+        deserialize_with_config, serialize_with_config,
+    };
+}
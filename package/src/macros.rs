@@ -0,0 +1,206 @@
+/// This macro declares a newtype of `RowID` for an entity kind,
+/// so that IDs of different kinds cannot be swapped by accident.
+///
+/// Each type has its own `RowIDWithConfig`,
+/// which is built once on first use and shared by every ID of the type.
+///
+/// With the `serde` feature, the type implements
+/// `Serialize` and `Deserialize` with its configuration.
+///
+/// ## Panics
+///
+/// The generated functions panic if the configuration is invalid.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{rowid_type, with_config::{Alphabet, RowIDWithConfig}};
+///
+/// rowid_type! {
+///     /// The ID of a user.
+///     pub struct UserID;
+/// }
+///
+/// rowid_type! {
+///     /// The ID of an order.
+///     pub struct OrderID = RowIDWithConfig::new()
+///         .alphabet(Alphabet::BASE62)
+///         .randomness_length(16)
+///         .prefix("ord_");
+/// }
+///
+/// let user: UserID = UserID::generate();
+/// let order: OrderID = OrderID::generate();
+///
+/// let parsed: OrderID = order.as_str().parse().unwrap();
+///
+/// assert!(parsed == order);
+/// ```
+#[macro_export]
+macro_rules! rowid_type {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+    ) => {
+        $crate::rowid_type! {
+            $(#[$meta])*
+            $vis struct $name = $crate::with_config::RowIDWithConfig::new();
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident = $config:expr;
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::cmp::PartialOrd,
+            ::core::cmp::Ord,
+            ::core::hash::Hash,
+        )]
+        $vis struct $name($crate::base::RowID);
+
+        impl $name {
+            /// Get the configuration of the type.
+            pub fn config() -> &'static $crate::with_config::RowIDWithConfigResult {
+                static CONFIG: ::std::sync::OnceLock<
+                    $crate::with_config::RowIDWithConfigResult,
+                > = ::std::sync::OnceLock::new();
+
+                CONFIG.get_or_init(|| {
+                    let config: $crate::with_config::RowIDWithConfig = $config;
+
+                    match config.done() {
+                        | ::core::result::Result::Ok(config) => config,
+                        | ::core::result::Result::Err(e) => ::core::panic!(
+                            "Invalid configuration of `{}`: {}",
+                            ::core::stringify!($name),
+                            e,
+                        ),
+                    }
+                })
+            }
+
+            /// Generates a unique ID with the configuration of the type.
+            pub fn generate() -> Self {
+                Self(Self::config().rowid_typed())
+            }
+
+            /// Get the ID as `&str`.
+            pub fn as_str(&self) -> &::core::primitive::str {
+                self.0.as_str()
+            }
+
+            /// Get the decoded timestamp part of the ID.
+            pub fn timestamp(&self) -> ::std::time::SystemTime {
+                self.0.timestamp()
+            }
+
+            /// Get the ID as `&RowID`.
+            pub fn as_row_id(&self) -> &$crate::base::RowID {
+                &self.0
+            }
+
+            /// Convert the ID into `RowID`.
+            pub fn into_row_id(self) -> $crate::base::RowID {
+                self.0
+            }
+
+            /// Convert the ID into `String`.
+            pub fn into_string(self) -> ::std::string::String {
+                self.0.into_string()
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::base::RowIDError;
+
+            fn from_str(
+                s: &::core::primitive::str,
+            ) -> ::core::result::Result<Self, Self::Err> {
+                Self::config().parse(s).map(Self)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(
+                &self,
+                f: &mut ::std::fmt::Formatter<'_>,
+            ) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::core::convert::AsRef<::core::primitive::str> for $name {
+            fn as_ref(&self) -> &::core::primitive::str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::convert::TryFrom<$crate::base::RowID> for $name {
+            type Error = $crate::base::RowIDError;
+
+            fn try_from(
+                id: $crate::base::RowID,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                id.as_str().parse()
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::base::RowID {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::convert::From<$name> for ::std::string::String {
+            fn from(id: $name) -> Self {
+                id.0.into_string()
+            }
+        }
+
+        $crate::__rowid_type_serde!($name);
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rowid_type_serde {
+    ($name:ident) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S: $crate::__private::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::__private::serialize_with_config(
+                    Self::config(),
+                    &self.0,
+                    serializer,
+                )
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                deserializer: D
+            ) -> ::core::result::Result<Self, D::Error> {
+                $crate::__private::deserialize_with_config(
+                    Self::config(),
+                    deserializer,
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rowid_type_serde {
+    ($name:ident) => {};
+}
@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use rand::{
+    Rng, RngCore, SeedableRng, TryRngCore,
+    rngs::{OsRng, StdRng},
+};
+
+/// A source of randomness for the randomness part of the ID.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::random::{RandomSource, ThreadRandom};
+///
+/// let index: usize = ThreadRandom.random_index(32);
+/// ```
+pub trait RandomSource: fmt::Debug + Send + Sync {
+    /// Returns a random index in the range of `0..upper`.
+    fn random_index(
+        &self,
+        upper: usize,
+    ) -> usize;
+
+    /// Fills the output with random indexes in the range of `0..upper`.
+    fn fill_indices(
+        &self,
+        upper: usize,
+        output: &mut [usize],
+    ) {
+        for index in output.iter_mut() {
+            *index = self.random_index(upper);
+        }
+    }
+}
+
+/// A random source backed by the thread-local generator of `rand`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadRandom;
+
+impl RandomSource for ThreadRandom {
+    fn random_index(
+        &self,
+        upper: usize,
+    ) -> usize {
+        rand::rng().random_range(0..upper)
+    }
+
+    fn fill_indices(
+        &self,
+        upper: usize,
+        output: &mut [usize],
+    ) {
+        let mut rng = rand::rng();
+
+        for index in output.iter_mut() {
+            *index = rng.random_range(0..upper);
+        }
+    }
+}
+
+/// A random source backed by the random number generator
+/// of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsRandom;
+
+impl RandomSource for OsRandom {
+    fn random_index(
+        &self,
+        upper: usize,
+    ) -> usize {
+        OsRng.unwrap_err().random_range(0..upper)
+    }
+
+    fn fill_indices(
+        &self,
+        upper: usize,
+        output: &mut [usize],
+    ) {
+        let mut rng = OsRng.unwrap_err();
+
+        for index in output.iter_mut() {
+            *index = rng.random_range(0..upper);
+        }
+    }
+}
+
+/// A random source backed by any `rand::RngCore`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rand::{SeedableRng, rngs::StdRng};
+/// use rowid::random::RngSource;
+///
+/// let source: RngSource<StdRng> = RngSource::new(StdRng::seed_from_u64(42));
+/// ```
+pub struct RngSource<R: RngCore + Send> {
+    rng: Mutex<R>,
+}
+
+impl<R: RngCore + Send> RngSource<R> {
+    /// Creates a new `RngSource` from the generator.
+    pub fn new(rng: R) -> Self {
+        Self { rng: Mutex::new(rng) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, R> {
+        self.rng.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl RngSource<StdRng> {
+    /// Creates a new seeded `RngSource`,
+    /// which produces the same randomness for the same seed.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rand::rngs::StdRng;
+    /// use rowid::random::RngSource;
+    ///
+    /// let source: RngSource<StdRng> = RngSource::seeded(42);
+    /// ```
+    pub fn seeded(seed: u64) -> Self {
+        Self::new(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: RngCore + Send> fmt::Debug for RngSource<R> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("RngSource").finish_non_exhaustive()
+    }
+}
+
+impl<R: RngCore + Send> RandomSource for RngSource<R> {
+    fn random_index(
+        &self,
+        upper: usize,
+    ) -> usize {
+        self.lock().random_range(0..upper)
+    }
+
+    fn fill_indices(
+        &self,
+        upper: usize,
+        output: &mut [usize],
+    ) {
+        let mut rng: MutexGuard<'_, R> = self.lock();
+
+        for index in output.iter_mut() {
+            *index = rng.random_range(0..upper);
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// This function converts the `SystemTime` to timestamp in milliseconds.
 ///
@@ -12,11 +16,12 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 /// let timestamp: usize = system_time_to_timestamp(now);
 /// ```
 pub fn system_time_to_timestamp<T: Into<SystemTime>>(system_time: T) -> usize {
-    system_time
-        .into()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_millis() as usize)
-        .unwrap_or(0)
+    system_time_to_timestamp_since(
+        system_time.into(),
+        UNIX_EPOCH,
+        Precision::Milliseconds,
+    )
+    .unwrap_or(0) as usize
 }
 
 /// This function converts timestamp in milliseconds to `SystemTime`.
@@ -33,3 +38,172 @@ pub fn system_time_to_timestamp<T: Into<SystemTime>>(system_time: T) -> usize {
 pub fn timestamp_to_system_time(timestamp: usize) -> SystemTime {
     UNIX_EPOCH + Duration::from_millis(timestamp as u64)
 }
+
+/// The unit of the timestamp in the ID.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use rowid::time::Precision;
+///
+/// let timestamp: u128 =
+///     Precision::Microseconds.duration_to_timestamp(Duration::from_millis(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precision {
+    /// Timestamp in seconds.
+    Seconds,
+    /// Timestamp in milliseconds.
+    #[default]
+    Milliseconds,
+    /// Timestamp in microseconds.
+    Microseconds,
+    /// Timestamp in nanoseconds.
+    Nanoseconds,
+}
+
+impl Precision {
+    /// Converts the duration into a timestamp in this precision,
+    /// the remainder is truncated.
+    pub fn duration_to_timestamp(
+        self,
+        duration: Duration,
+    ) -> u128 {
+        match self {
+            | Self::Seconds => duration.as_secs() as u128,
+            | Self::Milliseconds => duration.as_millis(),
+            | Self::Microseconds => duration.as_micros(),
+            | Self::Nanoseconds => duration.as_nanos(),
+        }
+    }
+
+    /// Converts the timestamp in this precision into a duration,
+    /// returns `None` if the duration overflows.
+    pub fn timestamp_to_duration(
+        self,
+        timestamp: u128,
+    ) -> Option<Duration> {
+        let timestamp: u64 = u64::try_from(timestamp).ok()?;
+
+        Some(match self {
+            | Self::Seconds => Duration::from_secs(timestamp),
+            | Self::Milliseconds => Duration::from_millis(timestamp),
+            | Self::Microseconds => Duration::from_micros(timestamp),
+            | Self::Nanoseconds => Duration::from_nanos(timestamp),
+        })
+    }
+}
+
+pub(crate) fn system_time_to_timestamp_since(
+    system_time: SystemTime,
+    epoch: SystemTime,
+    precision: Precision,
+) -> Option<u128> {
+    system_time
+        .duration_since(epoch)
+        .ok()
+        .map(|d| precision.duration_to_timestamp(d))
+}
+
+pub(crate) fn timestamp_to_system_time_since(
+    timestamp: u128,
+    epoch: SystemTime,
+    precision: Precision,
+) -> Option<SystemTime> {
+    epoch.checked_add(precision.timestamp_to_duration(timestamp)?)
+}
+
+pub(crate) fn truncate_system_time(
+    system_time: SystemTime,
+    epoch: SystemTime,
+    precision: Precision,
+) -> SystemTime {
+    system_time_to_timestamp_since(system_time, epoch, precision)
+        .and_then(|t| timestamp_to_system_time_since(t, epoch, precision))
+        .unwrap_or(epoch)
+}
+
+/// A source of the current time.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::SystemTime;
+/// use rowid::time::{Clock, SystemClock};
+///
+/// let now: SystemTime = SystemClock.now();
+/// ```
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// A clock that reads the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when it is told to,
+/// clones of it share the same time.
+///
+/// ## Example
+///
+/// ```no_run
+/// use std::time::{Duration, SystemTime};
+/// use rowid::time::{Clock, MockClock};
+///
+/// let clock: MockClock = MockClock::new(SystemTime::UNIX_EPOCH);
+/// clock.advance(Duration::from_millis(1));
+///
+/// let now: SystemTime = clock.now();
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    /// Creates a new `MockClock` starting at the given time.
+    pub fn new<T: Into<SystemTime>>(system_time: T) -> Self {
+        Self { now: Arc::new(Mutex::new(system_time.into())) }
+    }
+
+    /// Sets the current time of the clock.
+    pub fn set<T: Into<SystemTime>>(
+        &self,
+        system_time: T,
+    ) {
+        *self.lock() = system_time.into();
+    }
+
+    /// Moves the clock forward by the given duration.
+    pub fn advance(
+        &self,
+        duration: Duration,
+    ) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SystemTime> {
+        self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(UNIX_EPOCH)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.lock()
+    }
+}
@@ -0,0 +1,136 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::{
+    common::{
+        configs::{ALPHABET, TIMESTAMP_LENGTH, ULID_RANDOMNESS_LENGTH},
+        errors::RowIDError,
+        id::RowID,
+    },
+    functions::{
+        rowid::{_rowid, _rowid_typed, RowIDOptions},
+        ulid::{
+            _u128_to_ulid, _ulid_to_u128, FromUlidOptions, ToUlidOptions,
+            ulid_timestamp,
+        },
+    },
+    random::ThreadRandom,
+    time::{Precision, SystemClock},
+};
+
+/// This function generates a 26-character ULID,
+/// which is also a RowID with a randomness length of `16`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::ulid::ulid;
+///
+/// let id: String = ulid();
+/// ```
+pub fn ulid() -> String {
+    _rowid(RowIDOptions {
+        alphabet: &ALPHABET,
+        randomness_length: ULID_RANDOMNESS_LENGTH,
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        clock: &SystemClock,
+        random_source: &ThreadRandom,
+    })
+}
+
+/// This function generates a 26-character ULID as a `RowID`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::RowID, ulid::ulid_typed};
+///
+/// let id: RowID = ulid_typed();
+/// ```
+pub fn ulid_typed() -> RowID {
+    _rowid_typed(RowIDOptions {
+        alphabet: &ALPHABET,
+        randomness_length: ULID_RANDOMNESS_LENGTH,
+        epoch: UNIX_EPOCH,
+        precision: Precision::Milliseconds,
+        timestamp_length: TIMESTAMP_LENGTH,
+        clock: &SystemClock,
+        random_source: &ThreadRandom,
+    })
+}
+
+/// This function parses the ULID into a `RowID`,
+/// lowercase characters are accepted and converted to uppercase.
+///
+/// An error is returned if the ULID is not 26 characters long,
+/// or if its timestamp is larger than 48 bits.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::RowID, ulid::parse};
+///
+/// let id: RowID = parse("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+/// ```
+pub fn parse<S: AsRef<str>>(encoded: S) -> Result<RowID, RowIDError> {
+    to_u128(encoded).map(from_u128)
+}
+
+/// This function converts the ULID into a `u128`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::ulid::to_u128;
+///
+/// let value: u128 = to_u128("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+/// ```
+pub fn to_u128<S: AsRef<str>>(encoded: S) -> Result<u128, RowIDError> {
+    _ulid_to_u128(ToUlidOptions { encoded })
+}
+
+/// This function converts the `u128` into a ULID as a `RowID`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::RowID, ulid::from_u128};
+///
+/// let id: RowID = from_u128(0x0155_5555_5555_5555_5555_5555_5555_5555);
+/// ```
+pub fn from_u128(value: u128) -> RowID {
+    RowID::from_parts(
+        _u128_to_ulid(FromUlidOptions { value }),
+        UNIX_EPOCH + Duration::from_millis(ulid_timestamp(value)),
+        TIMESTAMP_LENGTH,
+    )
+}
+
+/// This function converts the ULID into its 16-byte binary form,
+/// the bytes are in big-endian order as defined by the ULID specification.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::ulid::to_bytes;
+///
+/// let bytes: [u8; 16] = to_bytes("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+/// ```
+pub fn to_bytes<S: AsRef<str>>(encoded: S) -> Result<[u8; 16], RowIDError> {
+    to_u128(encoded).map(u128::to_be_bytes)
+}
+
+/// This function converts the 16-byte binary form into a ULID as a `RowID`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::RowID, ulid::{from_bytes, to_bytes}};
+///
+/// let bytes: [u8; 16] = to_bytes("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+/// let id: RowID = from_bytes(bytes);
+/// ```
+pub fn from_bytes(bytes: [u8; 16]) -> RowID {
+    from_u128(u128::from_be_bytes(bytes))
+}
@@ -0,0 +1,55 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use ::uuid::Uuid;
+
+use crate::{
+    common::{configs::TIMESTAMP_LENGTH, errors::RowIDError, id::RowID},
+    functions::uuid::{
+        _from_uuid_v7, _to_uuid_v7, FromUuidOptions, ToUuidOptions,
+    },
+};
+
+/// This function converts the ID into a version 7 UUID,
+/// which keeps the timestamp in milliseconds
+/// and the first `14` characters of the randomness.
+///
+/// IDs generated by `RowIDWithConfig::uuid_v7` are converted without loss.
+///
+/// The ID must be at most `32` characters of the default `char_list`,
+/// the characters that are dropped are validated as well.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::rowid, uuid::to_uuid};
+/// use uuid::Uuid;
+///
+/// let uuid: Uuid = to_uuid(rowid()).unwrap();
+/// ```
+pub fn to_uuid<S: AsRef<str>>(encoded: S) -> Result<Uuid, RowIDError> {
+    _to_uuid_v7(ToUuidOptions { encoded }).map(Uuid::from_u128)
+}
+
+/// This function converts the version 7 UUID into a `RowID`,
+/// with a randomness length of `14`.
+///
+/// The last 4 bits of the randomness of the UUID do not fit,
+/// so they are dropped.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::{base::RowID, uuid::from_uuid};
+/// use uuid::Uuid;
+///
+/// let id: RowID = from_uuid(Uuid::now_v7()).unwrap();
+/// ```
+pub fn from_uuid(uuid: Uuid) -> Result<RowID, RowIDError> {
+    let value: u128 = uuid.as_u128();
+
+    Ok(RowID::from_parts(
+        _from_uuid_v7(FromUuidOptions { value })?,
+        UNIX_EPOCH + Duration::from_millis((value >> 80) as u64),
+        TIMESTAMP_LENGTH,
+    ))
+}
@@ -1,27 +1,210 @@
-use std::{io, time::SystemTime};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use crate::{
     common::{
-        configs::{CHAR_LIST, RANDOMNESS_LENGTH},
+        configs::{
+            CHAR_LIST, MIN_ALPHABET_LENGTH, MIN_CHAR_LIST_LENGTH, NODE_LENGTH,
+            RANDOMNESS_LENGTH, SEQUENCE_LENGTH, TIMESTAMP_LENGTH,
+            ULID_RANDOMNESS_LENGTH, UUID_RANDOMNESS_LENGTH, YEAR_10000,
+        },
         errors::RowIDError,
+        id::RowID,
     },
     functions::{
+        binary::{
+            _from_bytes, _from_u128, _to_bytes, _to_u128, FromBytesOptions,
+            FromU128Options, ToBytesOptions, ToU128Options,
+        },
+        checksum::{
+            _checksum, _strip_checksum, ChecksumOptions, validate_checksum,
+        },
         decode::{_decode, DecodeOptions},
-        encode::{_encode, EncodeOptions},
-        generate::{_generate, GenerateOptions, GenerateResult},
+        encode::{
+            _encode, EncodeOptions, check_timestamp, encode_fixed,
+            max_timestamp,
+        },
+        generate::{_generate_typed, GenerateOptions, GenerateResult},
         get_randomness::{_get_randomness, GetRandomnessOptions},
-        rowid::{_rowid, RowIDOptions},
-        verify::{_verify, VerifyOptions, VerifyResult},
+        node::{
+            _decode_parts, _encode_node, DecodePartsOptions, EncodeNodeOptions,
+            node_id_from_env,
+        },
+        normalize::{_normalize, NormalizeOptions},
+        parse::{_parse, ParseOptions},
+        prefix::{_strip_prefix, PrefixOptions},
+        rowid::{_rowid_typed, RowIDOptions},
+        shared::max_sequence_length,
+        verify::{_try_verify, Verified, VerifyOptions, VerifyResult},
+    },
+    random::{RandomSource, RngSource, ThreadRandom},
+    time::{
+        Clock, Precision, SystemClock, system_time_to_timestamp_since,
+        timestamp_to_system_time_since,
+    },
+};
+
+use rand::RngCore;
+
+pub use crate::{
+    common::alphabet::Alphabet,
+    functions::{
+        batch::RowIDIter,
+        checksum::Checksum,
+        monotonic::MonotonicGenerator,
+        node::DecodedParts,
+        sequence::{SequenceExhausted, SequenceGenerator},
+        shared::SharedGenerator,
+        verify::VerifyPolicy,
     },
 };
 
 /// This struct contains the state of the `RowIDWithConfig` struct.
+///
+/// With the `serde` feature, the state can be serialized
+/// without the `clock` and the `random_source`,
+/// which are restored to `SystemClock` and `ThreadRandom`.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RowIDWithConfigState {
     /// The list of characters used in the current function.
     pub char_list: String,
+    /// Whether the `char_list` must only contain ASCII characters.
+    pub ascii_only: bool,
+    /// Whether the `char_list` must be sorted to preserve the sort order.
+    pub preserve_sort_order: bool,
     /// The length of randomness used in the current function.
     pub randomness_length: usize,
+    /// The epoch that timestamps are measured from in the current function,
+    /// serialized as signed milliseconds since the Unix epoch.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::common::serde_impls::epoch_millis")
+    )]
+    pub epoch: SystemTime,
+    /// The precision of the timestamp used in the current function.
+    pub precision: Precision,
+    /// The length of timestamp used in the current function.
+    pub timestamp_length: usize,
+    /// The clock used to read the current time in the current function.
+    #[cfg_attr(feature = "serde", serde(skip, default = "default_clock"))]
+    pub clock: Arc<dyn Clock>,
+    /// The source of randomness used in the current function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(skip, default = "default_random_source")
+    )]
+    pub random_source: Arc<dyn RandomSource>,
+    /// The rules applied by the verification in the current function.
+    pub verify_policy: VerifyPolicy,
+    /// Whether the other case of the characters is accepted in decoding.
+    pub case_insensitive: bool,
+    /// The aliases accepted in decoding and the characters they point to.
+    pub aliases: Vec<(char, char)>,
+    /// The check symbol appended to the IDs in the current function.
+    pub checksum: Option<Checksum>,
+    /// The prefix of the IDs in the current function.
+    pub prefix: Option<String>,
+    /// The node ID embedded in the IDs in the current function.
+    pub node_id: Option<u64>,
+    /// The length of the node ID used in the current function.
+    pub node_length: usize,
+    /// The length of the sequence used by the sequence generators.
+    pub sequence_length: usize,
+    /// What the sequence generators do when the sequence is exhausted.
+    pub sequence_exhausted: SequenceExhausted,
+}
+
+#[cfg(feature = "serde")]
+fn default_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(feature = "serde")]
+fn default_random_source() -> Arc<dyn RandomSource> {
+    Arc::new(ThreadRandom)
+}
+
+/// The substitutions of Crockford's Base32,
+/// `I` and `L` are decoded as `1`, and `O` is decoded as `0`.
+pub const CROCKFORD_ALIASES: [(char, char); 3] =
+    [('I', '1'), ('L', '1'), ('O', '0')];
+
+fn max_system_time(
+    state: &RowIDWithConfigState,
+    timestamp_length: usize,
+) -> Option<SystemTime> {
+    timestamp_to_system_time_since(
+        max_timestamp(state.char_list.chars().count(), timestamp_length)?,
+        state.epoch,
+        state.precision,
+    )
+}
+
+/// Returns the shortest timestamp length that covers the year 10000.
+fn covering_timestamp_length(
+    char_list_length: usize,
+    epoch: SystemTime,
+    precision: Precision,
+) -> usize {
+    if char_list_length < MIN_ALPHABET_LENGTH {
+        return TIMESTAMP_LENGTH;
+    }
+
+    let timestamp: u128 = system_time_to_timestamp_since(
+        UNIX_EPOCH + Duration::from_secs(YEAR_10000),
+        epoch,
+        precision,
+    )
+    .unwrap_or(0);
+
+    (1..)
+        .find(|&length| {
+            max_timestamp(char_list_length, length)
+                .is_none_or(|max| max >= timestamp)
+        })
+        .unwrap_or(TIMESTAMP_LENGTH)
+}
+
+fn validate_char_list(
+    state: &RowIDWithConfigState,
+    min_length: usize,
+) -> Result<(), RowIDError> {
+    let char_list: Vec<char> = state.char_list.chars().collect();
+
+    if char_list.len() < min_length {
+        return Err(RowIDError::CharListLength {
+            expected: min_length,
+            actual: char_list.len(),
+        });
+    }
+
+    let mut seen: HashSet<char> = HashSet::with_capacity(char_list.len());
+
+    if let Some(&character) = char_list.iter().find(|&&c| !seen.insert(c)) {
+        return Err(RowIDError::CharListDuplicate { character });
+    }
+
+    if state.ascii_only {
+        if let Some(&character) = char_list.iter().find(|c| !c.is_ascii()) {
+            return Err(RowIDError::CharListNotAscii { character });
+        }
+    }
+
+    if state.preserve_sort_order {
+        if let Some(position) = char_list.windows(2).position(|w| w[0] >= w[1])
+        {
+            return Err(RowIDError::CharListNotSorted {
+                character: char_list[position + 1],
+                position: position + 1,
+            });
+        }
+    }
+
+    Ok(())
 }
 
 /// This struct contains different modified functions
@@ -39,12 +222,35 @@ pub struct RowIDWithConfigState {
 pub struct RowIDWithConfigResult {
     /// Represents function's customization configurations.
     pub state: RowIDWithConfigState,
+    alphabet: Alphabet,
+    node: Option<String>,
 }
 
 impl RowIDWithConfigResult {
+    /// This function returns the `Alphabet` built from the `char_list`,
+    /// it is built once in `done` and reused by every function.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     Alphabet, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let alphabet: &Alphabet = rwc.alphabet();
+    /// ```
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+
     /// This function generates a unique ID
     /// that is almost impossible to duplicate.
     ///
+    /// The timestamp saturates at the epoch and at `max_system_time`,
+    /// use `monotonic` to get an error instead.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -55,13 +261,110 @@ impl RowIDWithConfigResult {
     /// let id: String = rwc.rowid();
     /// ```
     pub fn rowid(&self) -> String {
-        _rowid(RowIDOptions {
-            char_list: &self.state.char_list,
+        self.rowid_typed().into_string()
+    }
+
+    /// This function generates a unique ID as a `RowID`.
+    ///
+    /// The timestamp saturates at the epoch and at `max_system_time`,
+    /// use `monotonic` to get an error instead.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::{
+    ///     base::RowID,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult}
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let id: RowID = rwc.rowid_typed();
+    /// ```
+    pub fn rowid_typed(&self) -> RowID {
+        self.add_affixes_typed(_rowid_typed(RowIDOptions {
+            alphabet: &self.alphabet,
             randomness_length: self.state.randomness_length,
-        })
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
+            clock: self.state.clock.as_ref(),
+            random_source: self.state.random_source.as_ref(),
+        }))
+    }
+
+    /// This function generates `n` unique IDs at once,
+    /// it reads the clock once per millisecond
+    /// and reuses one buffer for every ID.
+    ///
+    /// Use `rowid_batch_monotonic` for strictly increasing IDs.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let ids: Vec<String> = rwc.rowid_batch(1_000);
+    /// ```
+    pub fn rowid_batch(
+        &self,
+        n: usize,
+    ) -> Vec<String> {
+        self.rowid_iter().take(n).collect()
+    }
+
+    /// This function generates `n` strictly increasing unique IDs at once,
+    /// the randomness is incremented by one for each ID
+    /// within the same millisecond.
+    ///
+    /// When the randomness overflows, it waits for the clock
+    /// to move forward for up to one millisecond.
+    /// Fewer IDs are returned if the clock does not move forward by then,
+    /// or if the timestamp reaches `max_system_time`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let ids: Vec<String> = rwc.rowid_batch_monotonic(1_000);
+    ///
+    /// assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    pub fn rowid_batch_monotonic(
+        &self,
+        n: usize,
+    ) -> Vec<String> {
+        self.rowid_iter().monotonic(true).take(n).collect()
+    }
+
+    /// This function creates an endless iterator of unique IDs,
+    /// it reads the clock once per millisecond
+    /// and reuses one buffer for every ID.
+    ///
+    /// With `monotonic`, the iterator ends when the randomness overflows
+    /// and the clock does not move forward within one millisecond,
+    /// see `RowIDIter`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let ids: Vec<String> =
+    ///     rwc.rowid_iter().monotonic(true).take(1_000).collect();
+    /// ```
+    pub fn rowid_iter(&self) -> RowIDIter<'_> {
+        RowIDIter::new(self)
     }
 
-    /// This function encodes the timestamp in milliseconds
+    /// This function encodes the timestamp in the configured precision
     /// into an ID without randomness.
     ///
     /// ## Example
@@ -77,14 +380,18 @@ impl RowIDWithConfigResult {
     pub fn encode<T: Into<SystemTime>>(
         &self,
         system_time: T,
-    ) -> io::Result<String> {
+    ) -> Result<String, RowIDError> {
         _encode(EncodeOptions {
-            char_list: &self.state.char_list,
+            alphabet: &self.alphabet,
             system_time: system_time.into(),
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
         })
+        .map(|encoded| self.add_affixes(encoded))
     }
 
-    /// This function decodes the ID into a timestamp in milliseconds.
+    /// This function decodes the ID into a timestamp in the configured precision.
     ///
     /// ## Example
     ///
@@ -99,10 +406,16 @@ impl RowIDWithConfigResult {
     pub fn decode<S: AsRef<str>>(
         &self,
         encoded: S,
-    ) -> io::Result<SystemTime> {
+    ) -> Result<SystemTime, RowIDError> {
+        let (encoded, _): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
         _decode(DecodeOptions {
-            char_list: &self.state.char_list,
-            encoded: encoded.as_ref(),
+            alphabet: &self.alphabet,
+            encoded,
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
         })
     }
 
@@ -122,158 +435,232 @@ impl RowIDWithConfigResult {
     /// let now: SystemTime = SystemTime::now();
     /// let result: GenerateResult = rwc.generate(now, Some(22));
     /// ```
+    #[deprecated(since = "0.6.0", note = "Use `try_generate` instead")]
     pub fn generate<T: Into<SystemTime>>(
         &self,
         system_time: T,
         randomness_length: Option<usize>,
     ) -> GenerateResult {
-        _generate(GenerateOptions {
-            char_list: &self.state.char_list,
+        self.try_generate(system_time, randomness_length).into()
+    }
+
+    /// This function generates an ID based on the input.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let now: SystemTime = SystemTime::now();
+    /// let id: String = rwc.try_generate(now, Some(22)).unwrap();
+    /// ```
+    pub fn try_generate<T: Into<SystemTime>>(
+        &self,
+        system_time: T,
+        randomness_length: Option<usize>,
+    ) -> Result<String, RowIDError> {
+        self.generate_typed(system_time, randomness_length)
+            .map(RowID::into_string)
+    }
+
+    /// This function generates an ID based on the input as a `RowID`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// use rowid::{
+    ///     base::RowID,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult}
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let now: SystemTime = SystemTime::now();
+    /// let id: RowID = rwc.generate_typed(now, Some(22)).unwrap();
+    /// ```
+    pub fn generate_typed<T: Into<SystemTime>>(
+        &self,
+        system_time: T,
+        randomness_length: Option<usize>,
+    ) -> Result<RowID, RowIDError> {
+        _generate_typed(GenerateOptions {
+            alphabet: &self.alphabet,
             system_time: system_time.into(),
             randomness_length: match randomness_length {
                 | Some(l) => l,
                 | None => self.state.randomness_length,
             },
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
+            random_source: self.state.random_source.as_ref(),
         })
+        .map(|id| self.add_affixes_typed(id))
     }
 
-    /// This function verifies if the ID is valid and natural.
+    /// This function parses the ID into a `RowID`.
+    ///
+    /// Every character must be in the `char_list`,
+    /// and the ID must be exactly as long as the configuration.
     ///
     /// ## Example
     ///
     /// ```no_run
     /// use rowid::{
-    ///     base::VerifyResult,
+    ///     base::RowID,
     ///     with_config::{RowIDWithConfig, RowIDWithConfigResult}
     /// };
     ///
     /// let rwc: RowIDWithConfigResult =
-    ///     RowIDWithConfig::new().done().unwrap();
-    /// let result: VerifyResult = rwc.verify("ABC123");
+    ///     RowIDWithConfig::new().randomness_length(6).done().unwrap();
+    /// let id: RowID = rwc.parse("01JVS2Y5R8ABCDEF").unwrap();
     /// ```
-    pub fn verify<S: AsRef<str>>(
+    pub fn parse<S: AsRef<str>>(
         &self,
         encoded: S,
-    ) -> VerifyResult {
-        _verify(VerifyOptions {
-            char_list: &self.state.char_list,
-            encoded: encoded.as_ref(),
-        })
+    ) -> Result<RowID, RowIDError> {
+        let (encoded, check): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
+        let id: RowID = _parse(ParseOptions {
+            alphabet: &self.alphabet,
+            encoded,
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
+            length: self.length(),
+        })?;
+
+        let id: RowID = id.with_node_length(self.node_length());
+
+        Ok(self.add_prefix_typed(match check {
+            | Some(check) => id.with_checksum(check),
+            | None => id,
+        }))
     }
 
-    /// This function generates randomness.
+    /// This function verifies if the ID is valid and natural.
     ///
     /// ## Example
     ///
     /// ```no_run
-    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    /// use rowid::{
+    ///     base::VerifyResult,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult}
+    /// };
     ///
     /// let rwc: RowIDWithConfigResult =
     ///     RowIDWithConfig::new().done().unwrap();
-    /// let randomness: String = rwc.get_randomness(10);
+    /// let result: VerifyResult = rwc.verify("ABC123");
     /// ```
-    pub fn get_randomness(
+    #[deprecated(since = "0.6.0", note = "Use `try_verify` instead")]
+    pub fn verify<S: AsRef<str>>(
         &self,
-        randomness_length: usize,
-    ) -> String {
-        _get_randomness(GetRandomnessOptions {
-            char_list: &self.state.char_list,
-            randomness_length,
-        })
+        encoded: S,
+    ) -> VerifyResult {
+        self.try_verify(encoded).into()
     }
-}
-
-/// This struct allows you to create a new instance
-/// that contains different RowID functions
-/// and add different configuration functions
-/// to customize how RowID works.
-///
-/// ## Example
-///
-/// ```no_run
-/// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
-///
-/// let rwc: RowIDWithConfigResult =
-///     RowIDWithConfig::new().done().unwrap();
-/// ```
-#[derive(Debug, Clone)]
-pub struct RowIDWithConfig {
-    state: RowIDWithConfigState,
-}
 
-impl RowIDWithConfig {
-    /// Creates a new `RowIDWithConfig` with default values.
+    /// This function verifies if the ID is valid,
+    /// and returns the decoded time and whether it is natural.
     ///
     /// ## Example
     ///
     /// ```no_run
-    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    /// use rowid::{
+    ///     base::Verified,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult}
+    /// };
     ///
     /// let rwc: RowIDWithConfigResult =
     ///     RowIDWithConfig::new().done().unwrap();
+    /// let verified: Verified = rwc.try_verify("ABC123").unwrap();
     /// ```
-    pub fn new() -> Self {
-        Self {
-            state: RowIDWithConfigState {
-                char_list: CHAR_LIST.to_string(),
-                randomness_length: RANDOMNESS_LENGTH,
-            },
-        }
+    pub fn try_verify<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<Verified, RowIDError> {
+        let (encoded, _): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
+        _try_verify(VerifyOptions {
+            alphabet: &self.alphabet,
+            encoded,
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
+            randomness_length: self.node_length()
+                + self.state.randomness_length,
+            clock: self.state.clock.as_ref(),
+            policy: &self.state.verify_policy,
+        })
     }
 
-    /// The list of characters that can be used in the RowID,
-    /// The length of it must be greater or equal to `28`.
+    /// This function replaces the aliases and the other case of the characters
+    /// in the ID with the characters in the `char_list`.
     ///
-    /// default: `0123456789ABCDEFGHJKMNPQRSTVWXYZ`
+    /// An error is returned if the ID contains a character
+    /// that cannot be decoded.
     ///
     /// ## Example
     ///
     /// ```no_run
-    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    /// use rowid::with_config::{
+    ///     CROCKFORD_ALIASES, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
     ///
     /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
-    ///     .char_list("0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+    ///     .case_insensitive(true)
+    ///     .aliases(CROCKFORD_ALIASES)
     ///     .done()
     ///     .unwrap();
     ///
-    /// let id: String = rwc.rowid();
+    /// let id: String = rwc.normalize("o1jvs2y5r8").unwrap();
+    ///
+    /// assert!(id == "01JVS2Y5R8");
     /// ```
-    pub fn char_list<S: Into<String>>(
-        mut self,
-        list: S,
-    ) -> Self {
-        self.state.char_list = list.into();
-        self
+    pub fn normalize<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<String, RowIDError> {
+        let (encoded, check): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
+        _normalize(NormalizeOptions { alphabet: &self.alphabet, encoded }).map(
+            |mut normalized| {
+                normalized.extend(check);
+                self.add_prefix(normalized)
+            },
+        )
     }
 
-    /// The default length of randomness in the RowID,
-    /// it's recommended to be longer or equal to `6`
-    /// to avoid collision in the same timestamp.
-    ///
-    /// default: `22`
+    /// This function returns the latest time that can be encoded
+    /// with the configuration,
+    /// or `None` if it is later than `SystemTime` can represent.
     ///
     /// ## Example
     ///
     /// ```no_run
+    /// use std::time::SystemTime;
     /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
     ///
-    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
-    ///     .randomness_length(22)
-    ///     .done()
-    ///     .unwrap();
-    ///
-    /// let id: String = rwc.rowid();
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let max: Option<SystemTime> = rwc.max_system_time();
     /// ```
-    pub fn randomness_length(
-        mut self,
-        length: usize,
-    ) -> Self {
-        self.state.randomness_length = length;
-        self
+    pub fn max_system_time(&self) -> Option<SystemTime> {
+        max_system_time(&self.state, self.state.timestamp_length)
     }
 
-    /// This function ends the configuration of the `rowid_with_config` function,
-    /// and returns different modified functions based on the parameters.
+    /// This function converts the ID into compact bytes,
+    /// the order of the bytes is the same as the order of the ID.
+    ///
+    /// The length of `char_list` must be a power of 2,
+    /// and the ID must be as long as the configuration.
     ///
     /// ## Example
     ///
@@ -282,27 +669,1225 @@ impl RowIDWithConfig {
     ///
     /// let rwc: RowIDWithConfigResult =
     ///     RowIDWithConfig::new().done().unwrap();
-    /// let id: String = rwc.rowid();
+    /// let bytes: Vec<u8> = rwc.to_bytes(rwc.rowid()).unwrap();
     /// ```
-    pub fn done(self) -> io::Result<RowIDWithConfigResult> {
-        if self.state.char_list.len() < 28 {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                RowIDError::CharListLength.as_str(),
-            ));
-        }
+    pub fn to_bytes<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<Vec<u8>, RowIDError> {
+        let (encoded, _): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
 
-        Ok(RowIDWithConfigResult {
-            state: RowIDWithConfigState {
-                char_list: self.state.char_list,
-                randomness_length: self.state.randomness_length,
-            },
+        _to_bytes(ToBytesOptions {
+            alphabet: &self.alphabet,
+            length: self.length(),
+            encoded,
         })
     }
-}
 
-impl Default for RowIDWithConfig {
-    fn default() -> Self {
-        Self::new()
+    /// This function converts the compact bytes back into the ID.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let bytes: Vec<u8> = rwc.to_bytes(rwc.rowid()).unwrap();
+    /// let id: String = rwc.from_bytes(&bytes).unwrap();
+    /// ```
+    pub fn from_bytes<B: AsRef<[u8]>>(
+        &self,
+        bytes: B,
+    ) -> Result<String, RowIDError> {
+        _from_bytes(FromBytesOptions {
+            alphabet: &self.alphabet,
+            length: self.length(),
+            bytes: bytes.as_ref(),
+        })
+        .map(|encoded| self.add_affixes(encoded))
+    }
+
+    /// This function converts the ID into a `u128`,
+    /// the order of the numbers is the same as the order of the ID.
+    ///
+    /// The length of `char_list` must be a power of 2,
+    /// and the ID must fit in 128 bits,
+    /// for example, a randomness length of `15` or less
+    /// with the default `char_list`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().randomness_length(15).done().unwrap();
+    /// let value: u128 = rwc.to_u128(rwc.rowid()).unwrap();
+    /// ```
+    pub fn to_u128<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<u128, RowIDError> {
+        let (encoded, _): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
+        _to_u128(ToU128Options {
+            alphabet: &self.alphabet,
+            length: self.length(),
+            encoded,
+        })
+    }
+
+    /// This function converts the `u128` back into the ID.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().randomness_length(15).done().unwrap();
+    /// let value: u128 = rwc.to_u128(rwc.rowid()).unwrap();
+    /// let id: String = rwc.from_u128(value).unwrap();
+    /// ```
+    pub fn from_u128(
+        &self,
+        value: u128,
+    ) -> Result<String, RowIDError> {
+        _from_u128(FromU128Options {
+            alphabet: &self.alphabet,
+            length: self.length(),
+            value,
+        })
+        .map(|encoded| self.add_affixes(encoded))
+    }
+
+    /// This function decodes the timestamp, the node ID
+    /// and the randomness of the ID separately.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     DecodedParts, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().node_id(42).done().unwrap();
+    /// let parts: DecodedParts = rwc.decode_parts(rwc.rowid()).unwrap();
+    ///
+    /// assert!(parts.node_id == Some(42));
+    /// ```
+    pub fn decode_parts<S: AsRef<str>>(
+        &self,
+        encoded: S,
+    ) -> Result<DecodedParts, RowIDError> {
+        let (encoded, _): (&str, Option<char>) =
+            self.strip_affixes(encoded.as_ref())?;
+
+        _decode_parts(DecodePartsOptions {
+            alphabet: &self.alphabet,
+            encoded,
+            epoch: self.state.epoch,
+            precision: self.state.precision,
+            timestamp_length: self.state.timestamp_length,
+            node_length: self.node.as_ref().map(|_| self.state.node_length),
+        })
+    }
+
+    fn length(&self) -> usize {
+        self.state.timestamp_length
+            + self.node_length()
+            + self.state.randomness_length
+    }
+
+    fn node_length(&self) -> usize {
+        self.node.as_ref().map_or(0, |_| self.state.node_length)
+    }
+
+    pub(crate) fn check_symbol(
+        &self,
+        encoded: &str,
+    ) -> Option<char> {
+        self.state.checksum.map(|checksum| {
+            _checksum(ChecksumOptions {
+                alphabet: &self.alphabet,
+                checksum,
+                encoded,
+            })
+        })
+    }
+
+    fn add_prefix(
+        &self,
+        mut encoded: String,
+    ) -> String {
+        if let Some(prefix) = &self.state.prefix {
+            encoded.insert_str(0, prefix);
+        }
+
+        encoded
+    }
+
+    fn add_prefix_typed(
+        &self,
+        id: RowID,
+    ) -> RowID {
+        match &self.state.prefix {
+            | Some(prefix) => id.with_prefix(prefix),
+            | None => id,
+        }
+    }
+
+    fn add_affixes(
+        &self,
+        mut encoded: String,
+    ) -> String {
+        encoded.extend(self.check_symbol(&encoded));
+        self.add_prefix(encoded)
+    }
+
+    /// Inserts the node ID, then appends the check symbol
+    /// and prepends the prefix.
+    fn add_affixes_typed(
+        &self,
+        id: RowID,
+    ) -> RowID {
+        let id: RowID = match &self.node {
+            | Some(node) => id.with_node(node),
+            | None => id,
+        };
+
+        self.add_prefix_typed(match self.check_symbol(id.as_str()) {
+            | Some(check) => id.with_checksum(check),
+            | None => id,
+        })
+    }
+
+    /// Returns the node ID encoded in the IDs, if any.
+    pub(crate) fn encoded_node(&self) -> Option<&str> {
+        self.node.as_deref()
+    }
+
+    /// Reads the clock and returns the timestamp in the configured precision,
+    /// `SystemTimeBeforeEpoch` if the clock is before the epoch,
+    /// or `TimestampOverflow` if it does not fit in `timestamp_length`.
+    pub(crate) fn now_timestamp(&self) -> Result<u128, RowIDError> {
+        let timestamp: u128 = match system_time_to_timestamp_since(
+            self.state.clock.now(),
+            self.state.epoch,
+            self.state.precision,
+        ) {
+            | Some(timestamp) => timestamp,
+            | None => return Err(RowIDError::SystemTimeBeforeEpoch),
+        };
+
+        check_timestamp(&self.alphabet, timestamp, self.state.timestamp_length)
+    }
+
+    /// Encodes the timestamp before the body of the ID,
+    /// then adds the node ID, the check symbol and the prefix.
+    pub(crate) fn assemble_typed(
+        &self,
+        timestamp: u128,
+        body: &str,
+    ) -> RowID {
+        let system_time: SystemTime = timestamp_to_system_time_since(
+            timestamp,
+            self.state.epoch,
+            self.state.precision,
+        )
+        .unwrap_or(self.state.epoch);
+
+        let id: String = encode_fixed(
+            &self.alphabet,
+            timestamp,
+            self.state.timestamp_length,
+        ) + body;
+
+        self.add_affixes_typed(RowID::from_parts(
+            id,
+            system_time,
+            self.state.timestamp_length,
+        ))
+    }
+
+    /// Returns the ID without the prefix and the check symbol,
+    /// and the check symbol in its canonical form.
+    fn strip_affixes<'a>(
+        &self,
+        encoded: &'a str,
+    ) -> Result<(&'a str, Option<char>), RowIDError> {
+        let encoded: &'a str = match &self.state.prefix {
+            | Some(prefix) => _strip_prefix(PrefixOptions { prefix, encoded })?,
+            | None => encoded,
+        };
+
+        match self.state.checksum {
+            | Some(checksum) => _strip_checksum(ChecksumOptions {
+                alphabet: &self.alphabet,
+                checksum,
+                encoded,
+            })
+            .map(|(encoded, check)| (encoded, Some(check))),
+            | None => Ok((encoded, None)),
+        }
+    }
+
+    /// This function creates a `MonotonicGenerator`
+    /// that generates strictly increasing IDs.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     MonotonicGenerator, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let mut generator: MonotonicGenerator = rwc.monotonic();
+    /// let id: String = generator.rowid().unwrap();
+    /// ```
+    pub fn monotonic(&self) -> MonotonicGenerator {
+        MonotonicGenerator::new(self.clone())
+    }
+
+    /// This function creates a `SequenceGenerator`
+    /// that generates IDs with a sequence instead of randomness.
+    ///
+    /// The generator can be shared between threads.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     RowIDWithConfig, RowIDWithConfigResult, SequenceGenerator,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().node_id(7).done().unwrap();
+    /// let generator: SequenceGenerator = rwc.sequence();
+    /// let id: String = generator.rowid().unwrap();
+    /// ```
+    pub fn sequence(&self) -> SequenceGenerator {
+        SequenceGenerator::new(self.clone())
+    }
+
+    /// This function creates a `SharedGenerator`
+    /// that generates strictly increasing IDs without a lock,
+    /// the IDs contain a counter of `sequence_length` characters
+    /// before the randomness.
+    ///
+    /// The generator can be shared between threads.
+    ///
+    /// An error is returned if `sequence_length` was changed in the state
+    /// and the counter leaves no bits for the timestamp,
+    /// or if the clock is before the epoch.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use rowid::with_config::{
+    ///     RowIDWithConfig, RowIDWithConfigResult, SharedGenerator,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let generator: Arc<SharedGenerator> = Arc::new(rwc.shared().unwrap());
+    /// let id: String = generator.rowid().unwrap();
+    /// ```
+    pub fn shared(&self) -> Result<SharedGenerator, RowIDError> {
+        SharedGenerator::new(self.clone())
+    }
+
+    /// This function generates randomness.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let randomness: String = rwc.get_randomness(10);
+    /// ```
+    pub fn get_randomness(
+        &self,
+        randomness_length: usize,
+    ) -> String {
+        _get_randomness(GetRandomnessOptions {
+            alphabet: &self.alphabet,
+            randomness_length,
+            random_source: self.state.random_source.as_ref(),
+        })
+    }
+}
+
+/// This struct allows you to create a new instance
+/// that contains different RowID functions
+/// and add different configuration functions
+/// to customize how RowID works.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().done().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowIDWithConfig {
+    state: RowIDWithConfigState,
+    alphabet: Option<Alphabet>,
+    timestamp_length_set: bool,
+    node_id_env: Option<String>,
+}
+
+impl RowIDWithConfig {
+    /// Creates a new `RowIDWithConfig` with default values.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            state: RowIDWithConfigState {
+                char_list: CHAR_LIST.to_string(),
+                ascii_only: false,
+                preserve_sort_order: false,
+                randomness_length: RANDOMNESS_LENGTH,
+                epoch: UNIX_EPOCH,
+                precision: Precision::Milliseconds,
+                timestamp_length: TIMESTAMP_LENGTH,
+                clock: Arc::new(SystemClock),
+                random_source: Arc::new(ThreadRandom),
+                verify_policy: VerifyPolicy::new(),
+                case_insensitive: false,
+                aliases: Vec::new(),
+                checksum: None,
+                prefix: None,
+                node_id: None,
+                node_length: NODE_LENGTH,
+                sequence_length: SEQUENCE_LENGTH,
+                sequence_exhausted: SequenceExhausted::Wait,
+            },
+            alphabet: None,
+            timestamp_length_set: false,
+            node_id_env: None,
+        }
+    }
+
+    /// Creates a new `RowIDWithConfig` that generates ULIDs,
+    /// the randomness length is `16`,
+    /// and lowercase characters are accepted in decoding.
+    ///
+    /// The IDs are ULIDs as long as the `char_list`, the `epoch`,
+    /// the `precision` and the lengths are not changed,
+    /// use the `ulid` module to convert them into bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::ulid().done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    ///
+    /// assert!(id.len() == 26);
+    /// ```
+    pub fn ulid() -> Self {
+        Self::new()
+            .randomness_length(ULID_RANDOMNESS_LENGTH)
+            .case_insensitive(true)
+    }
+
+    /// Creates a new `RowIDWithConfig` that generates IDs
+    /// that can be converted into version 7 UUIDs and back without loss,
+    /// the randomness length is `14`.
+    ///
+    /// The IDs round-trip as long as the `char_list`, the `epoch`,
+    /// the `precision` and the lengths are not changed,
+    /// use the `uuid` module with the `uuid` feature to convert them.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::uuid_v7().done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    ///
+    /// assert!(id.len() == 24);
+    /// ```
+    pub fn uuid_v7() -> Self {
+        Self::new().randomness_length(UUID_RANDOMNESS_LENGTH)
+    }
+
+    /// The list of characters that can be used in the RowID,
+    /// The length of it must be greater or equal to `28`,
+    /// and it must not contain duplicate characters.
+    ///
+    /// default: `0123456789ABCDEFGHJKMNPQRSTVWXYZ`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .char_list("0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn char_list<S: Into<String>>(
+        mut self,
+        list: S,
+    ) -> Self {
+        self.state.char_list = list.into();
+        self.alphabet = None;
+        self
+    }
+
+    /// The alphabet that can be used in the RowID,
+    /// it replaces the `char_list`, and can be one of the presets,
+    /// such as `Alphabet::BASE58_BITCOIN`.
+    ///
+    /// Unlike `char_list`, the alphabet only needs `2` characters,
+    /// and the timestamp length defaults to the shortest one
+    /// that covers the year 10000 unless it is set.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     Alphabet, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .alphabet(Alphabet::BASE58_BITCOIN)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn alphabet(
+        mut self,
+        alphabet: Alphabet,
+    ) -> Self {
+        self.state.char_list = alphabet.as_str().to_string();
+        self.alphabet = Some(alphabet);
+        self
+    }
+
+    /// Requires the `char_list` to only contain ASCII characters.
+    ///
+    /// default: `false`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().ascii_only(true).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn ascii_only(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.state.ascii_only = enabled;
+        self
+    }
+
+    /// Requires the `char_list` to be sorted,
+    /// so that the IDs sort in the same order as their timestamps.
+    ///
+    /// default: `false`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().preserve_sort_order(true).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn preserve_sort_order(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.state.preserve_sort_order = enabled;
+        self
+    }
+
+    /// The default length of randomness in the RowID,
+    /// it's recommended to be longer or equal to `6`
+    /// to avoid collision in the same timestamp.
+    ///
+    /// default: `22`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .randomness_length(22)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn randomness_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.state.randomness_length = length;
+        self
+    }
+
+    /// The epoch that timestamps are measured from,
+    /// a later epoch keeps the timestamp smaller,
+    /// and an earlier epoch allows time before the Unix epoch.
+    ///
+    /// IDs are only compatible with configurations using the same epoch,
+    /// and `done` returns an error if the clock is before the epoch.
+    ///
+    /// default: `UNIX_EPOCH`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::{Duration, SystemTime};
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let epoch: SystemTime =
+    ///     SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().epoch(epoch).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn epoch<T: Into<SystemTime>>(
+        mut self,
+        epoch: T,
+    ) -> Self {
+        self.state.epoch = epoch.into();
+        self
+    }
+
+    /// The precision of the timestamp in the RowID,
+    /// a higher precision requires a longer timestamp
+    /// to represent the same range of time.
+    ///
+    /// IDs are only compatible with configurations using the same precision.
+    ///
+    /// default: `Precision::Milliseconds`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::{
+    ///     time::Precision,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult},
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .precision(Precision::Seconds)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn precision(
+        mut self,
+        precision: Precision,
+    ) -> Self {
+        self.state.precision = precision;
+        self
+    }
+
+    /// The length of the timestamp in the RowID,
+    /// the encoded time must not be later than `max_system_time`.
+    ///
+    /// IDs are only compatible with configurations using the same length.
+    ///
+    /// default: `10`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().timestamp_length(12).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn timestamp_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.state.timestamp_length = length;
+        self.timestamp_length_set = true;
+        self
+    }
+
+    /// This function returns the latest time that can be encoded
+    /// with the current configuration,
+    /// or `None` if it is later than `SystemTime` can represent.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// use rowid::{
+    ///     time::Precision,
+    ///     with_config::RowIDWithConfig,
+    /// };
+    ///
+    /// let max: Option<SystemTime> = RowIDWithConfig::new()
+    ///     .precision(Precision::Microseconds)
+    ///     .timestamp_length(12)
+    ///     .max_system_time();
+    /// ```
+    pub fn max_system_time(&self) -> Option<SystemTime> {
+        max_system_time(&self.state, self.resolved_timestamp_length())
+    }
+
+    fn resolved_timestamp_length(&self) -> usize {
+        match &self.alphabet {
+            | Some(alphabet) if !self.timestamp_length_set => {
+                covering_timestamp_length(
+                    alphabet.len(),
+                    self.state.epoch,
+                    self.state.precision,
+                )
+            },
+            | _ => self.state.timestamp_length,
+        }
+    }
+
+    /// The clock used to read the current time,
+    /// it's useful to replace it with a `MockClock` in tests.
+    ///
+    /// default: `SystemClock`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    /// use rowid::{
+    ///     time::MockClock,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult},
+    /// };
+    ///
+    /// let clock: MockClock = MockClock::new(SystemTime::UNIX_EPOCH);
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .clock(clock.clone())
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn clock<C: Clock + 'static>(
+        mut self,
+        clock: C,
+    ) -> Self {
+        self.state.clock = Arc::new(clock);
+        self
+    }
+
+    /// The source of randomness used in the randomness part of the ID.
+    ///
+    /// default: `ThreadRandom`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::{
+    ///     random::OsRandom,
+    ///     with_config::{RowIDWithConfig, RowIDWithConfigResult},
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().random_source(OsRandom).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn random_source<R: RandomSource + 'static>(
+        mut self,
+        random_source: R,
+    ) -> Self {
+        self.state.random_source = Arc::new(random_source);
+        self
+    }
+
+    /// The random number generator used in the randomness part of the ID,
+    /// a shortcut of `random_source` with `RngSource`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rand::{SeedableRng, rngs::StdRng};
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .rng(StdRng::seed_from_u64(42))
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn rng<R: RngCore + Send + 'static>(
+        self,
+        rng: R,
+    ) -> Self {
+        self.random_source(RngSource::new(rng))
+    }
+
+    /// The rules that an ID must follow to pass `try_verify`,
+    /// it can reject IDs that are too far in the future, too new, too old,
+    /// not as long as the configuration,
+    /// or containing randomness that is not in the `char_list`.
+    ///
+    /// default: `VerifyPolicy::new()`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use rowid::with_config::{
+    ///     RowIDWithConfig, RowIDWithConfigResult, VerifyPolicy,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .verify_policy(
+    ///         VerifyPolicy::new()
+    ///             .max_future_skew(Duration::from_secs(5))
+    ///             .exact_length(true)
+    ///             .check_randomness(true),
+    ///     )
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let valid: bool = rwc.try_verify(rwc.rowid()).is_ok();
+    /// ```
+    pub fn verify_policy(
+        mut self,
+        policy: VerifyPolicy,
+    ) -> Self {
+        self.state.verify_policy = policy;
+        self
+    }
+
+    /// Accepts the other case of the characters in decoding,
+    /// for example, `a` is decoded as `A`.
+    ///
+    /// Characters in the `char_list` always take precedence.
+    ///
+    /// default: `false`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().case_insensitive(true).done().unwrap();
+    ///
+    /// let id: String = rwc.normalize(rwc.rowid().to_lowercase()).unwrap();
+    /// ```
+    pub fn case_insensitive(
+        mut self,
+        enabled: bool,
+    ) -> Self {
+        self.state.case_insensitive = enabled;
+        self
+    }
+
+    /// Adds aliases that are accepted in decoding,
+    /// each alias is decoded as the character it points to.
+    ///
+    /// An alias must not be in the `char_list`,
+    /// and the character it points to must be in the `char_list`.
+    ///
+    /// default: `[]`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     CROCKFORD_ALIASES, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .case_insensitive(true)
+    ///     .aliases(CROCKFORD_ALIASES)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.normalize("o1jvs2y5r8").unwrap();
+    /// ```
+    pub fn aliases<I: IntoIterator<Item = (char, char)>>(
+        mut self,
+        aliases: I,
+    ) -> Self {
+        self.state.aliases.extend(aliases);
+        self
+    }
+
+    /// Appends a check symbol to the generated IDs,
+    /// IDs whose check symbol does not match are rejected in decoding.
+    ///
+    /// `Checksum::Crockford` requires a `char_list` of 32 characters
+    /// that does not contain its check symbols.
+    ///
+    /// default: `None`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     Checksum, RowIDWithConfig, RowIDWithConfigResult,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .checksum(Checksum::Crockford)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn checksum(
+        mut self,
+        checksum: Checksum,
+    ) -> Self {
+        self.state.checksum = Some(checksum);
+        self
+    }
+
+    /// Prepends a prefix to the generated IDs, such as `user_`,
+    /// IDs that do not start with the prefix are rejected in decoding.
+    ///
+    /// default: `None`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().prefix("user_").done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    ///
+    /// assert!(id.starts_with("user_"));
+    /// ```
+    pub fn prefix<S: Into<String>>(
+        mut self,
+        prefix: S,
+    ) -> Self {
+        self.state.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Embeds a node ID between the timestamp and the randomness,
+    /// so that IDs generated by different nodes never collide
+    /// within the same timestamp.
+    ///
+    /// The node ID is encoded in exactly `node_length` characters,
+    /// and it must be smaller than the length of `char_list`
+    /// to the power of `node_length`.
+    ///
+    /// default: `None`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().node_id(42).done().unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    ///
+    /// assert!(&id[10..12] == "1A");
+    /// ```
+    pub fn node_id(
+        mut self,
+        node_id: u64,
+    ) -> Self {
+        self.state.node_id = Some(node_id);
+        self.node_id_env = None;
+        self
+    }
+
+    /// Reads the node ID from the environment variable in `done`,
+    /// an error is returned if it is missing or not a valid integer.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .node_id_from_env("ROWID_NODE_ID")
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn node_id_from_env<S: Into<String>>(
+        mut self,
+        name: S,
+    ) -> Self {
+        self.node_id_env = Some(name.into());
+        self
+    }
+
+    /// The length of the node ID in the RowID,
+    /// it is only used when a node ID is set.
+    ///
+    /// IDs are only compatible with configurations using the same length.
+    ///
+    /// default: `2`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .node_id(4_000)
+    ///     .node_length(3)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn node_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.state.node_length = length;
+        self
+    }
+
+    /// The length of the sequence in the IDs of the `SequenceGenerator`
+    /// and the `SharedGenerator`,
+    /// it limits the number of IDs within the same timestamp.
+    ///
+    /// The `SharedGenerator` packs the timestamp and the sequence
+    /// into 64 bits, so a long sequence leaves less room for the timestamp,
+    /// and `done` returns an error if it leaves no room at all.
+    ///
+    /// The sequence takes the place of the randomness in these IDs,
+    /// so `try_verify` with `exact_length` rejects them
+    /// and `decode_parts` returns the sequence as part of the randomness,
+    /// use `parse` of the generator to read them.
+    ///
+    /// default: `3`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().sequence_length(2).done().unwrap();
+    ///
+    /// let id: String = rwc.sequence().rowid().unwrap();
+    /// ```
+    pub fn sequence_length(
+        mut self,
+        length: usize,
+    ) -> Self {
+        self.state.sequence_length = length;
+        self
+    }
+
+    /// What the `SequenceGenerator` and the `SharedGenerator` do
+    /// when the sequence is exhausted within the same timestamp.
+    ///
+    /// `SequenceExhausted::Wait` waits for the clock to move,
+    /// so it never returns with a clock that does not move, such as `MockClock`.
+    ///
+    /// default: `SequenceExhausted::Wait`
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{
+    ///     RowIDWithConfig, RowIDWithConfigResult, SequenceExhausted,
+    /// };
+    ///
+    /// let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+    ///     .sequence_exhausted(SequenceExhausted::Error)
+    ///     .done()
+    ///     .unwrap();
+    ///
+    /// let id: String = rwc.sequence().rowid().unwrap();
+    /// ```
+    pub fn sequence_exhausted(
+        mut self,
+        policy: SequenceExhausted,
+    ) -> Self {
+        self.state.sequence_exhausted = policy;
+        self
+    }
+
+    /// This function ends the configuration of the `rowid_with_config` function,
+    /// and returns different modified functions based on the parameters.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+    ///
+    /// let rwc: RowIDWithConfigResult =
+    ///     RowIDWithConfig::new().done().unwrap();
+    /// let id: String = rwc.rowid();
+    /// ```
+    pub fn done(self) -> Result<RowIDWithConfigResult, RowIDError> {
+        let timestamp_length: usize = self.resolved_timestamp_length();
+
+        let min_length: usize = match self.alphabet {
+            | Some(_) => MIN_ALPHABET_LENGTH,
+            | None => MIN_CHAR_LIST_LENGTH,
+        };
+
+        validate_char_list(&self.state, min_length)?;
+
+        let now: u128 = match system_time_to_timestamp_since(
+            self.state.clock.now(),
+            self.state.epoch,
+            self.state.precision,
+        ) {
+            | Some(now) => now,
+            | None => return Err(RowIDError::SystemTimeBeforeEpoch),
+        };
+
+        let alphabet: Alphabet = match self.alphabet {
+            | Some(alphabet) => alphabet,
+            | None => Alphabet::new(self.state.char_list.as_str()),
+        }
+        .with_aliases(self.state.case_insensitive, &self.state.aliases)?;
+
+        if let Some(checksum) = self.state.checksum {
+            validate_checksum(&alphabet, checksum)?;
+        }
+
+        let node_id: Option<u64> = match &self.node_id_env {
+            | Some(name) => Some(node_id_from_env(name)?),
+            | None => self.state.node_id,
+        };
+
+        let node: Option<String> = node_id
+            .map(|node_id| {
+                _encode_node(EncodeNodeOptions {
+                    alphabet: &alphabet,
+                    node_id,
+                    node_length: self.state.node_length,
+                })
+            })
+            .transpose()?;
+
+        let max: Option<u128> = max_timestamp(alphabet.len(), timestamp_length);
+
+        let max_length: usize = max_sequence_length(alphabet.len());
+
+        if self.state.sequence_length > max_length {
+            return Err(RowIDError::SequenceLength {
+                max: max_length,
+                actual: self.state.sequence_length,
+            });
+        }
+
+        if max.is_some_and(|max| now > max) {
+            return Err(RowIDError::TimestampOverflow { timestamp: Some(now) });
+        }
+
+        Ok(RowIDWithConfigResult {
+            state: RowIDWithConfigState {
+                char_list: self.state.char_list,
+                ascii_only: self.state.ascii_only,
+                preserve_sort_order: self.state.preserve_sort_order,
+                randomness_length: self.state.randomness_length,
+                epoch: self.state.epoch,
+                precision: self.state.precision,
+                timestamp_length,
+                clock: self.state.clock,
+                random_source: self.state.random_source,
+                verify_policy: self.state.verify_policy,
+                case_insensitive: self.state.case_insensitive,
+                aliases: self.state.aliases,
+                checksum: self.state.checksum,
+                prefix: self.state.prefix,
+                node_id,
+                node_length: self.state.node_length,
+                sequence_length: self.state.sequence_length,
+                sequence_exhausted: self.state.sequence_exhausted,
+            },
+            alphabet,
+            node,
+        })
+    }
+}
+
+impl Default for RowIDWithConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Restores the configuration from a state,
+/// such as the `state` of a `RowIDWithConfigResult`.
+///
+/// The `char_list` is used as an explicit alphabet,
+/// so it only needs `2` characters,
+/// and the `timestamp_length` is kept as it is.
+///
+/// ## Example
+///
+/// ```no_run
+/// use rowid::with_config::{RowIDWithConfig, RowIDWithConfigResult};
+///
+/// let rwc: RowIDWithConfigResult =
+///     RowIDWithConfig::new().done().unwrap();
+///
+/// let restored: RowIDWithConfigResult =
+///     RowIDWithConfig::from(rwc.state.clone()).done().unwrap();
+/// ```
+impl From<RowIDWithConfigState> for RowIDWithConfig {
+    fn from(state: RowIDWithConfigState) -> Self {
+        Self {
+            alphabet: Some(Alphabet::new(state.char_list.as_str())),
+            timestamp_length_set: true,
+            node_id_env: None,
+            state,
+        }
     }
 }
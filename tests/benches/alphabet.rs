@@ -0,0 +1,103 @@
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, SystemTime},
+};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rowid::{
+    base::{decode, encode, rowid},
+    time::timestamp_to_system_time,
+    with_config::{Alphabet, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+const CHAR_LIST: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Decodes the timestamp the way it was done
+/// before the alphabet was precompiled.
+fn decode_with_hash_map(encoded: &str) -> Option<u128> {
+    let char_index_map: HashMap<char, usize> =
+        CHAR_LIST.chars().enumerate().map(|(i, c)| (c, i)).collect();
+
+    encoded.chars().take(10).try_fold(0u128, |timestamp, c| {
+        Some(timestamp * 32 + *char_index_map.get(&c)? as u128)
+    })
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+    let id: String = rowid();
+
+    let mut group = c.benchmark_group("decode");
+
+    group.bench_function("hash_map", |b| {
+        b.iter(|| decode_with_hash_map(black_box(&id)))
+    });
+    group.bench_function("base", |b| b.iter(|| decode(black_box(&id))));
+    group.bench_function("with_config", |b| {
+        b.iter(|| rwc.decode(black_box(&id)))
+    });
+
+    group.finish();
+}
+
+fn bench_encode(c: &mut Criterion) {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+    let system_time: SystemTime =
+        timestamp_to_system_time(1_700_000_000_000) + Duration::from_millis(1);
+
+    let mut group = c.benchmark_group("encode");
+
+    group.bench_function("base", |b| b.iter(|| encode(black_box(system_time))));
+    group.bench_function("with_config", |b| {
+        b.iter(|| rwc.encode(black_box(system_time)))
+    });
+
+    group.finish();
+}
+
+fn bench_rowid(c: &mut Criterion) {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+
+    let mut group = c.benchmark_group("rowid");
+
+    group.bench_function("base", |b| b.iter(rowid));
+    group.bench_function("with_config", |b| b.iter(|| rwc.rowid()));
+
+    group.finish();
+}
+
+fn bench_index_of(c: &mut Criterion) {
+    let ascii: Alphabet = Alphabet::new(CHAR_LIST);
+    let wide: Alphabet = Alphabet::new("0123456789ABCDEFGHJKMNPQRSTVWXYZ£");
+
+    let mut group = c.benchmark_group("index_of");
+
+    group.bench_function("ascii", |b| {
+        b.iter(|| {
+            CHAR_LIST
+                .chars()
+                .map(|c| ascii.index_of(black_box(c)))
+                .collect::<Vec<Option<usize>>>()
+        })
+    });
+    group.bench_function("wide", |b| {
+        b.iter(|| {
+            CHAR_LIST
+                .chars()
+                .map(|c| wide.index_of(black_box(c)))
+                .collect::<Vec<Option<usize>>>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_decode,
+    bench_encode,
+    bench_rowid,
+    bench_index_of
+);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use std::time::SystemTime;
+
+use rowid::{
+    time::timestamp_to_system_time,
+    with_config::{Alphabet, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+const CHAR_LIST: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[test]
+fn test_alphabet_ascii() {
+    let alphabet: Alphabet = Alphabet::new(CHAR_LIST);
+
+    assert!(alphabet.is_ascii());
+    assert!(alphabet.len() == 32);
+    assert!(alphabet.as_str() == CHAR_LIST);
+
+    for (i, c) in CHAR_LIST.chars().enumerate() {
+        assert!(alphabet.char_at(i) == c);
+        assert!(alphabet.index_of(c) == Some(i));
+    }
+
+    assert!(alphabet.index_of('I').is_none());
+    assert!(alphabet.index_of('a').is_none());
+    assert!(alphabet.index_of('é').is_none());
+}
+
+#[test]
+fn test_alphabet_non_ascii() {
+    let char_list: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ£€";
+    let alphabet: Alphabet = Alphabet::new(char_list);
+
+    assert!(!alphabet.is_ascii());
+    assert!(alphabet.len() == 34);
+
+    for (i, c) in char_list.chars().enumerate() {
+        assert!(alphabet.char_at(i) == c);
+        assert!(alphabet.index_of(c) == Some(i));
+    }
+
+    assert!(alphabet.index_of('I').is_none());
+}
+
+#[test]
+fn test_alphabet_from_static() {
+    const ALPHABET: Alphabet = Alphabet::from_static(CHAR_LIST);
+
+    assert!(ALPHABET == Alphabet::new(CHAR_LIST));
+    assert!(ALPHABET.index_of('Z') == Some(31));
+}
+
+#[test]
+#[should_panic]
+fn test_alphabet_from_static_duplicate() {
+    let _ = Alphabet::from_static("0123456789ABCDEFGHJKMNPQRSTVWXYZ0");
+}
+
+#[test]
+fn test_rowid_with_config_alphabet() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+
+    assert!(rwc.alphabet().as_str() == rwc.state.char_list);
+}
+
+#[test]
+fn test_rowid_with_config_alphabet_non_ascii() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .char_list("0123456789ABCDEFGHJKMNPQRSTVWXYZ£€")
+        .done()
+        .unwrap();
+
+    let system_time: SystemTime = timestamp_to_system_time(1_700_000_000_000);
+    let encoded: String = rwc.encode(system_time).unwrap();
+
+    assert!(rwc.decode(&encoded).unwrap() == system_time);
+}
@@ -2,8 +2,8 @@ use std::{io, time::SystemTime};
 
 use rowid::{
     base::{
-        GenerateResult, RowIDError, VerifyResult, decode, encode, generate,
-        get_randomness, rowid, verify,
+        GenerateResult, RowIDError, Verified, VerifyResult, decode, encode,
+        get_randomness, rowid, try_generate, try_verify,
     },
     time::{system_time_to_timestamp, timestamp_to_system_time},
 };
@@ -69,30 +69,33 @@ fn test_decode() {
 
 #[test]
 fn test_decode_length_error() {
-    let result: io::Error = match decode("ABC123") {
+    let result: RowIDError = match decode("ABC123") {
         | Ok(_) => return assert!(false),
         | Err(e) => e,
     };
 
-    assert!(result.kind() == io::ErrorKind::InvalidInput);
-    assert!(result.to_string() == RowIDError::EncodedLength.as_str());
+    assert!(result == RowIDError::EncodedLength { expected: 10, actual: 6 });
 }
 
 #[test]
 fn test_decode_invalid_input_error() {
-    let result: io::Error = match decode("ab^!@#$agastgyaSER") {
+    let result: RowIDError = match decode("ab^!@#$agastgyaSER") {
         | Ok(_) => return assert!(false),
         | Err(e) => e,
     };
 
-    assert!(result.kind() == io::ErrorKind::InvalidInput);
-    assert!(result.to_string() == RowIDError::InvalidEncoded.as_str());
+    assert!(
+        result == RowIDError::InvalidEncoded { character: 'a', position: 0 }
+    );
 }
 
 // generate
 
 #[test]
+#[allow(deprecated)]
 fn test_generate() {
+    use rowid::base::generate;
+
     let current: SystemTime = SystemTime::now();
     let generated: GenerateResult = generate(current, Some(6));
     let id: String = generated.result.unwrap();
@@ -104,12 +107,54 @@ fn test_generate() {
     assert!(id.len() == 16);
 }
 
+#[test]
+fn test_try_generate() {
+    let current: SystemTime = SystemTime::now();
+    let id: String = try_generate(current, Some(6)).unwrap();
+
+    assert!(id.len() == 16);
+    assert!(
+        system_time_to_timestamp(decode(&id).unwrap())
+            == system_time_to_timestamp(current)
+    );
+}
+
+#[test]
+fn test_generate_result_from() {
+    let generated: GenerateResult =
+        try_generate(SystemTime::now(), None).into();
+
+    assert!(generated.success);
+    assert!(generated.result.is_some());
+    assert!(generated.error.is_none());
+
+    let generated: GenerateResult =
+        Err(RowIDError::SystemTimeBeforeEpoch).into();
+
+    assert!(!generated.success);
+    assert!(generated.result.is_none());
+
+    let error: io::Error = match generated.error {
+        | None => return assert!(false),
+        | Some(e) => e,
+    };
+
+    assert!(error.kind() == io::ErrorKind::InvalidInput);
+    assert!(
+        error.get_ref().and_then(|e| e.downcast_ref::<RowIDError>())
+            == Some(&RowIDError::SystemTimeBeforeEpoch)
+    );
+}
+
 // verify
 
 #[test]
+#[allow(deprecated)]
 fn test_verify() {
+    use rowid::base::verify;
+
     let current: SystemTime = SystemTime::now();
-    let id: String = generate(current, Some(6)).result.unwrap();
+    let id: String = try_generate(current, Some(6)).unwrap();
     let verified: VerifyResult = verify(&id);
     assert!(verified.success == true);
     assert!(match verified.result {
@@ -121,7 +166,10 @@ fn test_verify() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_verify_length_error() {
+    use rowid::base::verify;
+
     let verified: VerifyResult = verify("ABC123");
 
     assert!(verified.success == false);
@@ -132,11 +180,18 @@ fn test_verify_length_error() {
     };
 
     assert!(error.kind() == io::ErrorKind::InvalidInput);
-    assert!(error.to_string() == RowIDError::EncodedLength.as_str());
+    assert!(
+        error.to_string()
+            == RowIDError::EncodedLength { expected: 10, actual: 6 }
+                .to_string()
+    );
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_verify_invalid_input_error() {
+    use rowid::base::verify;
+
     let verified: VerifyResult = verify("ab^!@#$agastgyaSER");
 
     assert!(verified.success == false);
@@ -147,5 +202,77 @@ fn test_verify_invalid_input_error() {
     };
 
     assert!(error.kind() == io::ErrorKind::InvalidInput);
-    assert!(error.to_string() == RowIDError::InvalidEncoded.as_str());
+    assert!(
+        error.to_string()
+            == RowIDError::InvalidEncoded { character: 'a', position: 0 }
+                .to_string()
+    );
+}
+
+#[test]
+fn test_try_verify() {
+    let current: SystemTime = SystemTime::now();
+    let id: String = try_generate(current, Some(6)).unwrap();
+    let verified: Verified = try_verify(&id).unwrap();
+
+    assert!(
+        system_time_to_timestamp(verified.time)
+            == system_time_to_timestamp(current)
+    );
+    assert!(verified.natural);
+}
+
+#[test]
+fn test_try_verify_error() {
+    let err: RowIDError = match try_verify("ABC123") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 10, actual: 6 });
+}
+
+#[test]
+fn test_verify_result_from() {
+    let verified: VerifyResult =
+        try_verify(try_generate(SystemTime::now(), None).unwrap()).into();
+
+    assert!(verified.success);
+    assert!(verified.result.is_some());
+    assert!(verified.natural == Some(true));
+    assert!(verified.error.is_none());
+
+    let verified: VerifyResult = try_verify("ABC123").into();
+
+    assert!(!verified.success);
+    assert!(verified.result.is_none());
+    assert!(verified.natural.is_none());
+    assert!(verified.error.is_some());
+}
+
+// errors
+
+#[test]
+fn test_error_display() {
+    let err: RowIDError = decode("ABC123").unwrap_err();
+
+    assert!(
+        err.to_string()
+            == "Encoded length does not match the expected length (expected 10, got 6)"
+    );
+
+    let err: RowIDError = decode("0000000I00").unwrap_err();
+
+    assert!(err.to_string() == "Encoded is not valid ('I' at position 7)");
+}
+
+#[test]
+fn test_error_into_io_error() {
+    let err: io::Error = decode("ABC123").unwrap_err().into();
+
+    assert!(err.kind() == io::ErrorKind::InvalidInput);
+
+    let inner: &RowIDError = err.get_ref().unwrap().downcast_ref().unwrap();
+
+    assert!(*inner == RowIDError::EncodedLength { expected: 10, actual: 6 });
 }
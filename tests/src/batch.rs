@@ -0,0 +1,186 @@
+use std::{collections::HashSet, thread, time::Duration};
+
+use rowid::{
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{
+        Checksum, DecodedParts, RowIDIter, RowIDWithConfig,
+        RowIDWithConfigResult,
+    },
+};
+
+#[test]
+fn test_rowid_batch() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+
+    let ids: Vec<String> = rwc.rowid_batch(10_000);
+
+    assert!(ids.len() == 10_000);
+    assert!(ids.iter().all(|id| id.len() == 32));
+    assert!(ids.iter().collect::<HashSet<&String>>().len() == 10_000);
+    assert!(ids.iter().all(|id| rwc.try_verify(id).is_ok()));
+    assert!(rwc.rowid_batch(0).is_empty());
+}
+
+#[test]
+fn test_rowid_iter_clock_refresh() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+
+    for monotonic in [false, true] {
+        let mut iter: RowIDIter = rwc.rowid_iter().monotonic(monotonic);
+        let first: String = iter.next().unwrap();
+
+        clock.advance(Duration::from_millis(1));
+        thread::sleep(Duration::from_millis(2));
+
+        let second: String = iter.next().unwrap();
+
+        assert!(first[..10] < second[..10]);
+    }
+}
+
+#[test]
+fn test_rowid_batch_monotonic() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let ids: Vec<String> = rwc.rowid_batch_monotonic(10_000);
+
+    assert!(ids.len() == 10_000);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert!(rwc.rowid_batch_monotonic(0).is_empty());
+}
+
+#[test]
+fn test_rowid_batch_affixes() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(9)
+        .prefix("evt_")
+        .checksum(Checksum::Crockford)
+        .done()
+        .unwrap();
+
+    for id in rwc.rowid_batch(100) {
+        let parts: DecodedParts = rwc.decode_parts(&id).unwrap();
+
+        assert!(id.starts_with("evt_"));
+        assert!(parts.node_id == Some(9));
+        assert!(parts.randomness.len() == 22);
+        assert!(rwc.parse(&id).is_ok());
+    }
+}
+
+#[test]
+fn test_rowid_iter_monotonic() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let ids: Vec<String> =
+        rwc.rowid_iter().monotonic(true).take(10_000).collect();
+
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_rowid_iter_monotonic_overflow() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(1)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    let mut iter: RowIDIter = rwc.rowid_iter().monotonic(true);
+    let mut ids: Vec<String> = Vec::new();
+
+    // The randomness overflows after at most 32 IDs.
+    while ids.last().is_none_or(|id| !id.ends_with('Z')) {
+        ids.push(iter.next().unwrap());
+    }
+
+    clock.advance(Duration::from_millis(1));
+
+    ids.push(iter.next().unwrap());
+
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert!(ids.last().unwrap().starts_with("00000000Z9"));
+}
+
+#[test]
+fn test_rowid_batch_timestamp_saturates() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(3)
+        .randomness_length(1)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    clock.advance(Duration::from_secs(1_000_000));
+
+    assert!(rwc.rowid_batch(100).iter().all(|id| id.starts_with("ZZZ")));
+
+    let ids: Vec<String> = rwc.rowid_iter().monotonic(true).take(100).collect();
+
+    assert!(!ids.is_empty() && ids.len() <= 32);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_rowid_iter_monotonic_clock_stopped() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(0)
+        .clock(clock)
+        .done()
+        .unwrap();
+
+    assert!(rwc.rowid_batch_monotonic(3) == vec!["00000000Z8"]);
+
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(1)
+        .clock(MockClock::new(timestamp_to_system_time(1_000)))
+        .done()
+        .unwrap();
+
+    let ids: Vec<String> = rwc.rowid_batch_monotonic(100);
+
+    assert!(!ids.is_empty() && ids.len() <= 32);
+    assert!(ids.last().unwrap().ends_with('Z'));
+}
+
+#[test]
+fn test_rowid_iter_first_clock_read() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+
+    let mut iter: RowIDIter = rwc.rowid_iter();
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(iter.next().unwrap().starts_with("00000000Z9"));
+}
+
+#[test]
+fn test_rowid_iter_next_into() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(6)
+        .prefix("evt_")
+        .done()
+        .unwrap();
+
+    let mut iter: RowIDIter = rwc.rowid_iter().monotonic(true);
+    let mut encoded: String = String::from("stale");
+    let mut ids: Vec<String> = Vec::new();
+
+    for _ in 0..100 {
+        assert!(iter.next_into(&mut encoded));
+
+        ids.push(encoded.clone());
+    }
+
+    assert!(ids.iter().all(|id| id.starts_with("evt_") && id.len() == 20));
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
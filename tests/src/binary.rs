@@ -0,0 +1,127 @@
+use rowid::{
+    base::{RowIDError, from_bytes, rowid, to_bytes, try_generate},
+    time::timestamp_to_system_time,
+    with_config::{RowIDWithConfig, RowIDWithConfigResult},
+};
+
+// to_bytes + from_bytes
+
+#[test]
+fn test_bytes() {
+    let id: String = rowid();
+    let bytes: Vec<u8> = to_bytes(&id).unwrap();
+
+    assert!(bytes.len() == 20);
+    assert!(from_bytes(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_bytes_zero() {
+    let bytes: Vec<u8> = to_bytes("0".repeat(32)).unwrap();
+    assert!(bytes == vec![0; 20]);
+}
+
+#[test]
+fn test_bytes_order() {
+    let older: String =
+        try_generate(timestamp_to_system_time(1_000), None).unwrap();
+    let newer: String =
+        try_generate(timestamp_to_system_time(2_000), None).unwrap();
+
+    assert!(to_bytes(older).unwrap() < to_bytes(newer).unwrap());
+}
+
+#[test]
+fn test_bytes_length_error() {
+    let err: RowIDError = match to_bytes("ABC123") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 32, actual: 6 });
+
+    let err: RowIDError = match from_bytes([0; 16]) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::BinaryLength { expected: 160, actual: 128 });
+}
+
+#[test]
+fn test_bytes_with_config_padding() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let id: String = rwc.rowid();
+    let bytes: Vec<u8> = rwc.to_bytes(&id).unwrap();
+
+    assert!(bytes.len() == 10);
+    assert!(rwc.from_bytes(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_bytes_char_list_error() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .char_list("0123456789acdefghjkmnpqrtvwxy")
+        .done()
+        .unwrap();
+
+    let err: RowIDError = match rwc.to_bytes(rwc.rowid()) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::BinaryCharList { actual: 29 });
+}
+
+// to_u128 + from_u128
+
+#[test]
+fn test_u128() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(15).done().unwrap();
+
+    let id: String = rwc.rowid();
+    let value: u128 = rwc.to_u128(&id).unwrap();
+
+    assert!(rwc.from_u128(value).unwrap() == id);
+}
+
+#[test]
+fn test_u128_order() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(15).done().unwrap();
+
+    let older: String =
+        rwc.try_generate(timestamp_to_system_time(1_000), None).unwrap();
+    let newer: String =
+        rwc.try_generate(timestamp_to_system_time(2_000), None).unwrap();
+
+    assert!(rwc.to_u128(older).unwrap() < rwc.to_u128(newer).unwrap());
+}
+
+#[test]
+fn test_u128_width_error() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+
+    let err: RowIDError = match rwc.to_u128(rwc.rowid()) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::BinaryWidth { bits: 160 });
+}
+
+#[test]
+fn test_u128_value_error() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let err: RowIDError = match rwc.from_u128(1 << 100) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::BinaryLength { expected: 80, actual: 101 });
+}
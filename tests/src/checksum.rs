@@ -0,0 +1,138 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rowid::{
+    base::{RowID, RowIDError},
+    with_config::{
+        Alphabet, Checksum, MonotonicGenerator, RowIDWithConfig,
+        RowIDWithConfigResult,
+    },
+};
+
+const CHAR_LIST: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[test]
+fn test_checksum_rowid() {
+    for checksum in [Checksum::LuhnModN, Checksum::Crockford] {
+        let rwc: RowIDWithConfigResult =
+            RowIDWithConfig::new().checksum(checksum).done().unwrap();
+
+        let id: String = rwc.rowid();
+
+        assert!(id.len() == 33);
+        assert!(rwc.try_verify(&id).is_ok());
+        assert!(rwc.decode(&id).is_ok());
+    }
+}
+
+#[test]
+fn test_checksum_luhn_single_typo() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::LuhnModN).done().unwrap();
+
+    let id: Vec<char> = rwc.rowid().chars().collect();
+
+    for position in 0..id.len() {
+        for c in CHAR_LIST.chars().filter(|&c| c != id[position]) {
+            let mut typo: Vec<char> = id.clone();
+            typo[position] = c;
+
+            let typo: String = typo.into_iter().collect();
+
+            assert!(rwc.try_verify(&typo).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_checksum_mismatch_error() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::LuhnModN).done().unwrap();
+
+    assert!(rwc.encode(UNIX_EPOCH).unwrap() == "00000000000");
+
+    let err: RowIDError = match rwc.decode("00000000001") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::ChecksumMismatch { expected: '0', actual: '1' });
+}
+
+#[test]
+fn test_checksum_crockford_symbols() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::Crockford).done().unwrap();
+
+    let time: SystemTime = UNIX_EPOCH + Duration::from_millis(36);
+
+    assert!(
+        rwc.encode(UNIX_EPOCH + Duration::from_millis(38)).unwrap()
+            == "00000000161"
+    );
+    assert!(rwc.encode(time).unwrap() == "0000000014U");
+    assert!(rwc.decode("0000000014U").unwrap() == time);
+    assert!(rwc.decode("0000000014u").unwrap() == time);
+    assert!(rwc.decode("0000000014*").is_err());
+}
+
+#[test]
+fn test_checksum_crockford_char_list_error() {
+    let err: RowIDError = match RowIDWithConfig::new()
+        .alphabet(Alphabet::BASE62)
+        .checksum(Checksum::Crockford)
+        .done()
+    {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::ChecksumCharList);
+}
+
+#[test]
+fn test_checksum_parse() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::LuhnModN).done().unwrap();
+
+    let generated: RowID = rwc.rowid_typed();
+    let parsed: RowID = rwc.parse(generated.as_str()).unwrap();
+
+    assert!(parsed == generated);
+    assert!(parsed.randomness().len() == 22);
+    assert!(parsed.checksum() == generated.as_str().chars().last());
+}
+
+#[test]
+fn test_checksum_normalize() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .case_insensitive(true)
+        .checksum(Checksum::Crockford)
+        .done()
+        .unwrap();
+
+    assert!(rwc.normalize("0000000014u").unwrap() == "0000000014U");
+}
+
+#[test]
+fn test_checksum_monotonic() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::LuhnModN).done().unwrap();
+
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    for _ in 0..10 {
+        assert!(rwc.try_verify(generator.rowid().unwrap()).is_ok());
+    }
+}
+
+#[test]
+fn test_checksum_bytes() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().checksum(Checksum::LuhnModN).done().unwrap();
+
+    let id: String = rwc.rowid();
+    let bytes: Vec<u8> = rwc.to_bytes(&id).unwrap();
+
+    assert!(bytes.len() == 20);
+    assert!(rwc.from_bytes(&bytes).unwrap() == id);
+}
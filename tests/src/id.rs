@@ -0,0 +1,123 @@
+use std::{collections::HashSet, str::FromStr, time::SystemTime};
+
+use rowid::{
+    base::{RowID, RowIDError, encode, generate_typed, rowid_typed},
+    time::{system_time_to_timestamp, timestamp_to_system_time},
+    with_config::{RowIDWithConfig, RowIDWithConfigResult},
+};
+
+#[test]
+fn test_rowid_typed() {
+    let id: RowID = rowid_typed();
+    assert!(id.as_str().len() == 32);
+    assert!(id.encoded_timestamp().len() == 10);
+    assert!(id.randomness().len() == 22);
+}
+
+#[test]
+fn test_rowid_typed_parse() {
+    let id: RowID = rowid_typed();
+    let parsed: RowID = id.to_string().parse().unwrap();
+    assert!(parsed == id);
+    assert!(parsed.timestamp() == id.timestamp());
+}
+
+#[test]
+fn test_rowid_typed_timestamp() {
+    let current: SystemTime = SystemTime::now();
+    let id: RowID = generate_typed(current, Some(6)).unwrap();
+    assert!(
+        system_time_to_timestamp(id.timestamp())
+            == system_time_to_timestamp(current)
+    );
+    assert!(id.encoded_timestamp() == encode(current).unwrap());
+    assert!(id.randomness().len() == 6);
+}
+
+#[test]
+fn test_rowid_typed_ord() {
+    let older: RowID =
+        generate_typed(timestamp_to_system_time(1_000), None).unwrap();
+    let newer: RowID =
+        generate_typed(timestamp_to_system_time(2_000), None).unwrap();
+    assert!(older < newer);
+}
+
+#[test]
+fn test_rowid_typed_hash() {
+    let id: RowID = rowid_typed();
+    let mut set: HashSet<RowID> = HashSet::new();
+    set.insert(id.clone());
+    assert!(set.contains(&RowID::from_str(id.as_ref()).unwrap()));
+}
+
+#[test]
+fn test_rowid_typed_parse_error() {
+    let err: RowIDError = match "ABC123".parse::<RowID>() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 10, actual: 6 });
+}
+
+#[test]
+fn test_rowid_typed_with_config() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+    let parsed: RowID = rwc.parse(&id).unwrap();
+
+    assert!(id.as_str().len() == 16);
+    assert!(parsed == id);
+    assert!(String::from(parsed) == id.into_string());
+}
+
+#[test]
+fn test_rowid_typed_parse_invalid_character_error() {
+    let err: RowIDError = match "01JVS2Y5R8 hello!!".parse::<RowID>() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: ' ', position: 10 });
+
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(1).done().unwrap();
+
+    let err: RowIDError = match rwc.parse("01JVS2Y5R8~") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: '~', position: 10 });
+}
+
+#[test]
+fn test_rowid_typed_parse_length_error() {
+    let err: RowIDError = match "01JVS2Y5R8".parse::<RowID>() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 32, actual: 10 });
+
+    let err: RowIDError = match format!("{}0", rowid_typed()).parse::<RowID>() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 32, actual: 33 });
+
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_id(1).randomness_length(6).done().unwrap();
+
+    let err: RowIDError = match rwc.parse("01JVS2Y5R8ABCDEF") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 18, actual: 16 });
+    assert!(rwc.parse("01JVS2Y5R801ABCDEF").is_ok());
+}
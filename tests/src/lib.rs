@@ -2,7 +2,78 @@
 pub mod time;
 
 #[cfg(test)]
+#[allow(
+    clippy::assertions_on_constants,
+    clippy::bool_assert_comparison,
+    clippy::bool_comparison,
+    clippy::len_zero,
+    clippy::needless_borrows_for_generic_args
+)]
 pub mod base;
 
 #[cfg(test)]
+#[allow(
+    clippy::assertions_on_constants,
+    clippy::bool_assert_comparison,
+    clippy::bool_comparison,
+    clippy::len_zero,
+    clippy::needless_borrows_for_generic_args
+)]
 pub mod with_config;
+
+#[cfg(test)]
+pub mod id;
+
+#[cfg(test)]
+pub mod monotonic;
+
+#[cfg(test)]
+pub mod random;
+
+#[cfg(test)]
+pub mod binary;
+
+#[cfg(test)]
+pub mod alphabet;
+
+#[cfg(test)]
+pub mod verify;
+
+#[cfg(test)]
+pub mod normalize;
+
+#[cfg(test)]
+pub mod presets;
+
+#[cfg(test)]
+pub mod checksum;
+
+#[cfg(test)]
+pub mod prefix;
+
+#[cfg(test)]
+pub mod rowid_type;
+
+#[cfg(test)]
+pub mod serialize;
+
+#[cfg(test)]
+pub mod ulid_interop;
+
+#[cfg(test)]
+pub mod uuid_interop;
+
+#[cfg(test)]
+pub mod node;
+
+#[cfg(test)]
+pub mod sequence;
+
+#[cfg(all(test, not(loom)))]
+pub mod shared;
+
+#[cfg(all(test, loom))]
+pub mod shared_loom;
+
+#[cfg(test)]
+pub mod batch;
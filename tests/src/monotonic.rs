@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use rowid::{
+    base::{RowID, RowIDError},
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{MonotonicGenerator, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+#[test]
+fn test_monotonic_rowid() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let id: String = generator.rowid().unwrap();
+
+    assert!(id.len() == 32);
+}
+
+#[test]
+fn test_monotonic_rowid_ordering() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let ids: Vec<RowID> =
+        (0..10_000).map(|_| generator.rowid_typed().unwrap()).collect();
+
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_monotonic_rowid_same_millisecond() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(6)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let first: RowID = generator.rowid_typed().unwrap();
+    let second: RowID = generator.rowid_typed().unwrap();
+
+    assert!(first.encoded_timestamp() == second.encoded_timestamp());
+    assert!(first < second);
+
+    clock.advance(Duration::from_millis(1));
+
+    let third: RowID = generator.rowid_typed().unwrap();
+
+    assert!(second.encoded_timestamp() < third.encoded_timestamp());
+}
+
+#[test]
+fn test_monotonic_rowid_clock_backwards() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let first: RowID = generator.rowid_typed().unwrap();
+
+    clock.set(timestamp_to_system_time(500));
+
+    let second: RowID = generator.rowid_typed().unwrap();
+
+    assert!(first < second);
+}
+
+#[test]
+fn test_monotonic_rowid_overflow_error() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(0)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    assert!(generator.rowid().is_ok());
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::RandomnessOverflow);
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(generator.rowid().is_ok());
+}
+
+#[test]
+fn test_monotonic_timestamp_overflow() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(3)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    assert!(generator.rowid().is_ok());
+
+    clock.advance(Duration::from_secs(1_000_000));
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampOverflow { timestamp: Some(1_000_001_000) }
+    );
+}
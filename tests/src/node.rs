@@ -0,0 +1,182 @@
+use std::{env, time::SystemTime};
+
+use rowid::{
+    base::{RowID, RowIDError},
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{
+        Checksum, DecodedParts, MonotonicGenerator, RowIDWithConfig,
+        RowIDWithConfigResult,
+    },
+};
+
+#[test]
+fn test_node_rowid() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_id(42).done().unwrap();
+
+    let id: String = rwc.rowid();
+
+    assert!(id.len() == 34);
+    assert!(&id[10..12] == "1A");
+    assert!(rwc.try_verify(&id).is_ok());
+
+    let parts: DecodedParts = rwc.decode_parts(&id).unwrap();
+
+    assert!(parts.timestamp == rwc.decode(&id).unwrap());
+    assert!(parts.node_id == Some(42));
+    assert!(parts.randomness == id[12..]);
+}
+
+#[test]
+fn test_node_rowid_typed() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(7)
+        .node_length(3)
+        .randomness_length(6)
+        .done()
+        .unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(id.as_str().len() == 19);
+    assert!(id.encoded_node() == "007");
+    assert!(id.randomness().len() == 6);
+
+    let parsed: RowID = rwc.parse(id.as_str()).unwrap();
+
+    assert!(parsed.encoded_node() == "007");
+    assert!(parsed.randomness() == id.randomness());
+}
+
+#[test]
+fn test_node_without_node_id() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_length(4).done().unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(id.as_str().len() == 32);
+    assert!(id.encoded_node().is_empty());
+
+    let parts: DecodedParts = rwc.decode_parts(id.as_str()).unwrap();
+
+    assert!(parts.node_id.is_none());
+    assert!(parts.randomness == id.randomness());
+}
+
+#[test]
+fn test_node_with_affixes() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(1)
+        .prefix("user_")
+        .checksum(Checksum::Crockford)
+        .done()
+        .unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(id.encoded_node() == "01");
+    assert!(id.randomness().len() == 22);
+
+    let parts: DecodedParts = rwc.decode_parts(id.as_str()).unwrap();
+
+    assert!(parts.node_id == Some(1));
+    assert!(parts.randomness == id.randomness());
+}
+
+#[test]
+fn test_node_monotonic() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(1023)
+        .randomness_length(4)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+    let first: RowID = generator.rowid_typed().unwrap();
+    let second: RowID = generator.rowid_typed().unwrap();
+
+    assert!(first < second);
+    assert!(first.encoded_node() == "ZZ");
+    assert!(second.encoded_node() == "ZZ");
+    assert!(rwc.decode_parts(second.as_str()).unwrap().node_id == Some(1023));
+}
+
+#[test]
+fn test_node_bytes() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_id(5).done().unwrap();
+
+    let id: String = rwc.rowid();
+    let bytes: Vec<u8> = rwc.to_bytes(&id).unwrap();
+
+    assert!(rwc.from_bytes(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_node_env() {
+    unsafe {
+        env::set_var("ROWID_TEST_NODE_ID", " 17 ");
+        env::set_var("ROWID_TEST_NODE_ID_INVALID", "node-17");
+    }
+
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id_from_env("ROWID_TEST_NODE_ID")
+        .done()
+        .unwrap();
+
+    assert!(rwc.state.node_id == Some(17));
+    assert!(rwc.decode_parts(rwc.rowid()).unwrap().node_id == Some(17));
+
+    for name in ["ROWID_TEST_NODE_ID_INVALID", "ROWID_TEST_NODE_ID_MISSING"] {
+        let err: RowIDError =
+            match RowIDWithConfig::new().node_id_from_env(name).done() {
+                | Ok(_) => panic!("expected an error"),
+                | Err(e) => e,
+            };
+
+        assert!(err == RowIDError::NodeIDEnv);
+    }
+
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id_from_env("ROWID_TEST_NODE_ID_MISSING")
+        .node_id(3)
+        .done()
+        .unwrap();
+
+    assert!(rwc.state.node_id == Some(3));
+}
+
+#[test]
+fn test_node_errors() {
+    let err: RowIDError = match RowIDWithConfig::new().node_id(1024).done() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::NodeIDOverflow { node_id: 1024 });
+
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_id(1).done().unwrap();
+
+    let err: RowIDError = match rwc.decode_parts("00000000000") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 12, actual: 11 });
+
+    let err: RowIDError = match rwc.decode_parts("00000000000U") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: 'U', position: 11 });
+
+    let now: SystemTime = SystemTime::now();
+
+    assert!(rwc.decode_parts(rwc.encode(now).unwrap()).is_err());
+}
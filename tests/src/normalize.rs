@@ -0,0 +1,101 @@
+use std::time::SystemTime;
+
+use rowid::{
+    base::{RowID, RowIDError},
+    time::timestamp_to_system_time,
+    with_config::{CROCKFORD_ALIASES, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+fn crockford() -> RowIDWithConfigResult {
+    RowIDWithConfig::new()
+        .randomness_length(6)
+        .case_insensitive(true)
+        .aliases(CROCKFORD_ALIASES)
+        .done()
+        .unwrap()
+}
+
+#[test]
+fn test_decode_strict_by_default() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new().done().unwrap();
+
+    let err: RowIDError = match rwc.decode("01jvs2y5r8") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: 'j', position: 2 });
+}
+
+#[test]
+fn test_decode_case_insensitive() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().case_insensitive(true).done().unwrap();
+
+    let system_time: SystemTime = timestamp_to_system_time(1_700_000_000_000);
+    let encoded: String = rwc.encode(system_time).unwrap();
+
+    assert!(rwc.decode(encoded.to_lowercase()).unwrap() == system_time);
+    assert!(rwc.decode("000000000O").is_err());
+}
+
+#[test]
+fn test_decode_aliases() {
+    let rwc: RowIDWithConfigResult = crockford();
+
+    assert!(
+        rwc.decode("OoOoOoOoIl").unwrap() == rwc.decode("0000000011").unwrap()
+    );
+}
+
+#[test]
+fn test_decode_aliases_case_sensitive() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().aliases(CROCKFORD_ALIASES).done().unwrap();
+
+    assert!(rwc.decode("000000000O").is_ok());
+    assert!(rwc.decode("000000000o").is_err());
+}
+
+#[test]
+fn test_normalize() {
+    let rwc: RowIDWithConfigResult = crockford();
+
+    assert!(rwc.normalize("o1jvs2y5r8iLab").unwrap() == "01JVS2Y5R811AB");
+
+    let err: RowIDError = match rwc.normalize("01JVS2Y5R8U") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: 'U', position: 10 });
+}
+
+#[test]
+fn test_parse_normalized() {
+    let rwc: RowIDWithConfigResult = crockford();
+
+    let id: RowID = rwc.parse("o1jvs2y5r8abcdef").unwrap();
+
+    assert!(id.as_str() == "01JVS2Y5R8ABCDEF");
+    assert!(id == rwc.parse("01JVS2Y5R8ABCDEF").unwrap());
+}
+
+#[test]
+fn test_aliases_error() {
+    let err: RowIDError =
+        match RowIDWithConfig::new().aliases([('A', '1')]).done() {
+            | Ok(_) => panic!(),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::InvalidAlias { alias: 'A', character: '1' });
+
+    let err: RowIDError =
+        match RowIDWithConfig::new().aliases([('!', 'U')]).done() {
+            | Ok(_) => panic!(),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::InvalidAlias { alias: '!', character: 'U' });
+}
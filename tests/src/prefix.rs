@@ -0,0 +1,155 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rowid::{
+    base::{PrefixedRowID, RowID, RowIDError, RowIDPrefix, rowid_prefixed},
+    with_config::{
+        Checksum, MonotonicGenerator, RowIDWithConfig, RowIDWithConfigResult,
+    },
+};
+
+struct User;
+
+impl RowIDPrefix for User {
+    const PREFIX: &'static str = "user_";
+}
+
+struct Order;
+
+impl RowIDPrefix for Order {
+    const PREFIX: &'static str = "ord_";
+}
+
+#[test]
+fn test_prefix_rowid() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let id: String = rwc.rowid();
+
+    assert!(id.starts_with("user_"));
+    assert!(id.len() == 37);
+    assert!(rwc.try_verify(&id).is_ok());
+    assert!(rwc.decode(&id).is_ok());
+}
+
+#[test]
+fn test_prefix_encode_decode() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let now: SystemTime = SystemTime::now();
+    let encoded: String = rwc.encode(now).unwrap();
+
+    assert!(encoded.starts_with("user_"));
+    assert!(rwc.decode(&encoded).unwrap() <= now);
+    assert!(rwc.encode(UNIX_EPOCH).unwrap() == "user_0000000000");
+}
+
+#[test]
+fn test_prefix_mismatch_error() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let err: RowIDError = match rwc.decode("usr_0000000000") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::PrefixMismatch { position: 2 });
+
+    let err: RowIDError = match rwc.try_verify("0000000000") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::PrefixMismatch { position: 0 });
+}
+
+#[test]
+fn test_prefix_parse() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .prefix("user_")
+        .checksum(Checksum::LuhnModN)
+        .done()
+        .unwrap();
+
+    let generated: RowID = rwc.rowid_typed();
+    let parsed: RowID = rwc.parse(generated.as_str()).unwrap();
+
+    assert!(parsed == generated);
+    assert!(parsed.prefix() == "user_");
+    assert!(parsed.encoded_timestamp().len() == 10);
+    assert!(parsed.randomness().len() == 22);
+    assert!(parsed.checksum().is_some());
+}
+
+#[test]
+fn test_prefix_bytes() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let id: String = rwc.rowid();
+    let bytes: Vec<u8> = rwc.to_bytes(&id).unwrap();
+
+    assert!(rwc.from_bytes(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_prefix_monotonic() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let first: String = generator.rowid().unwrap();
+    let second: String = generator.rowid().unwrap();
+
+    assert!(first.starts_with("user_"));
+    assert!(first < second);
+}
+
+#[test]
+fn test_prefixed_rowid() {
+    let id: PrefixedRowID<User> = rowid_prefixed();
+
+    assert!(id.as_str().starts_with("user_"));
+    assert!(id.as_row_id().prefix() == "user_");
+
+    let parsed: PrefixedRowID<User> = id.as_str().parse().unwrap();
+
+    assert!(parsed == id);
+    assert!(parsed.timestamp() == id.timestamp());
+}
+
+#[test]
+fn test_prefixed_rowid_mismatch_error() {
+    let id: PrefixedRowID<User> = rowid_prefixed();
+
+    let err: RowIDError = match id.as_str().parse::<PrefixedRowID<Order>>() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::PrefixMismatch { position: 0 });
+}
+
+#[test]
+fn test_prefixed_rowid_try_from() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(PrefixedRowID::<User>::try_from(id.clone()).is_ok());
+    assert!(PrefixedRowID::<Order>::try_from(id).is_err());
+
+    let id: RowID =
+        RowIDWithConfig::new().prefix("user_v2_").done().unwrap().rowid_typed();
+
+    let err: RowIDError = match PrefixedRowID::<User>::try_from(id) {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::PrefixMismatch { position: 5 });
+}
@@ -0,0 +1,133 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rowid::{
+    base::RowIDError,
+    with_config::{Alphabet, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+const YEAR_10000: u64 = 253_402_300_800;
+
+#[test]
+fn test_presets_sort_order() {
+    assert!(Alphabet::CROCKFORD32.preserves_sort_order());
+    assert!(Alphabet::BASE58_BITCOIN.preserves_sort_order());
+    assert!(Alphabet::BASE62.preserves_sort_order());
+    assert!(Alphabet::BASE36_LOWER.preserves_sort_order());
+    assert!(Alphabet::BASE64_URL_SORTED.preserves_sort_order());
+    assert!(Alphabet::HEX_LOWER.preserves_sort_order());
+    assert!(!Alphabet::BASE64_URL.preserves_sort_order());
+}
+
+#[test]
+fn test_presets_bits_per_char() {
+    assert!(Alphabet::CROCKFORD32.bits_per_char() == 5.0);
+    assert!(Alphabet::BASE64_URL.bits_per_char() == 6.0);
+    assert!(Alphabet::HEX_LOWER.bits_per_char() == 4.0);
+    assert!((Alphabet::BASE58_BITCOIN.bits_per_char() - 5.858).abs() < 0.001);
+    assert!((Alphabet::BASE62.bits_per_char() - 5.954).abs() < 0.001);
+    assert!((Alphabet::BASE36_LOWER.bits_per_char() - 5.170).abs() < 0.001);
+}
+
+#[test]
+fn test_presets_timestamp_length() {
+    let cases: [(Alphabet, usize); 4] = [
+        (Alphabet::CROCKFORD32, 10),
+        (Alphabet::BASE58_BITCOIN, 9),
+        (Alphabet::BASE62, 9),
+        (Alphabet::HEX_LOWER, 12),
+    ];
+
+    for (alphabet, length) in cases {
+        let rwc: RowIDWithConfigResult =
+            RowIDWithConfig::new().alphabet(alphabet).done().unwrap();
+
+        assert!(rwc.rowid().chars().count() == length + 22);
+    }
+}
+
+#[test]
+fn test_presets_cover_year_10000() {
+    let year_10000: SystemTime = UNIX_EPOCH + Duration::from_secs(YEAR_10000);
+
+    for alphabet in [
+        Alphabet::CROCKFORD32,
+        Alphabet::BASE58_BITCOIN,
+        Alphabet::BASE62,
+        Alphabet::BASE36_LOWER,
+        Alphabet::BASE64_URL,
+        Alphabet::HEX_LOWER,
+    ] {
+        let rwc: RowIDWithConfig = RowIDWithConfig::new().alphabet(alphabet);
+
+        assert!(rwc.max_system_time().unwrap() >= year_10000);
+
+        let rwc: RowIDWithConfigResult = rwc.done().unwrap();
+        let encoded: String = rwc.encode(year_10000).unwrap();
+
+        assert!(rwc.decode(encoded).unwrap() == year_10000);
+    }
+}
+
+#[test]
+fn test_presets_explicit_timestamp_length() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .alphabet(Alphabet::HEX_LOWER)
+        .timestamp_length(14)
+        .randomness_length(8)
+        .done()
+        .unwrap();
+
+    assert!(rwc.rowid().len() == 22);
+}
+
+#[test]
+fn test_presets_sorted_rowid() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().alphabet(Alphabet::BASE62).done().unwrap();
+
+    let earlier: String = rwc.encode(UNIX_EPOCH).unwrap();
+    let later: String = rwc.encode(SystemTime::now()).unwrap();
+
+    assert!(earlier < later);
+}
+
+#[test]
+fn test_presets_unsorted_error() {
+    let err: RowIDError = match RowIDWithConfig::new()
+        .alphabet(Alphabet::BASE64_URL)
+        .preserve_sort_order(true)
+        .done()
+    {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::CharListNotSorted { character: '0', position: 52 }
+    );
+}
+
+#[test]
+fn test_presets_char_list_resets_alphabet() {
+    let err: RowIDError = match RowIDWithConfig::new()
+        .alphabet(Alphabet::HEX_LOWER)
+        .char_list("0123456789abcdef")
+        .done()
+    {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::CharListLength { expected: 28, actual: 16 });
+}
+
+#[test]
+fn test_presets_alphabet_length_error() {
+    let err: RowIDError =
+        match RowIDWithConfig::new().alphabet(Alphabet::new("0")).done() {
+            | Ok(_) => panic!("expected an error"),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::CharListLength { expected: 2, actual: 1 });
+}
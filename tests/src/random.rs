@@ -0,0 +1,58 @@
+use rowid::{
+    random::{OsRandom, RandomSource, RngSource},
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{RowIDWithConfig, RowIDWithConfigResult},
+};
+
+#[derive(Debug)]
+struct ZeroRandom;
+
+impl RandomSource for ZeroRandom {
+    fn random_index(
+        &self,
+        _upper: usize,
+    ) -> usize {
+        0
+    }
+}
+
+#[test]
+fn test_random_source_custom() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().random_source(ZeroRandom).done().unwrap();
+
+    assert!(rwc.get_randomness(6) == "000000");
+    assert!(rwc.rowid().ends_with(&"0".repeat(22)));
+}
+
+#[test]
+fn test_random_source_os() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().random_source(OsRandom).done().unwrap();
+
+    assert!(rwc.get_randomness(10).len() == 10);
+    assert!(rwc.rowid().len() == 32);
+}
+
+#[test]
+fn test_random_source_seeded() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+
+    let a: RowIDWithConfigResult = RowIDWithConfig::new()
+        .clock(clock.clone())
+        .random_source(RngSource::seeded(42))
+        .done()
+        .unwrap();
+    let b: RowIDWithConfigResult = RowIDWithConfig::new()
+        .clock(clock.clone())
+        .random_source(RngSource::seeded(42))
+        .done()
+        .unwrap();
+
+    assert!(a.rowid() == b.rowid());
+    assert!(a.get_randomness(10) == b.get_randomness(10));
+    assert!(
+        a.try_generate(timestamp_to_system_time(2_000), None).ok()
+            == b.try_generate(timestamp_to_system_time(2_000), None).ok()
+    );
+}
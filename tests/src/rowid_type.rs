@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use rowid::{
+    base::{RowID, RowIDError},
+    rowid_type,
+    with_config::{Alphabet, Checksum, RowIDWithConfig},
+};
+
+rowid_type! {
+    /// The ID of a user.
+    pub struct UserID;
+}
+
+rowid_type! {
+    /// The ID of an order.
+    pub struct OrderID = RowIDWithConfig::new()
+        .alphabet(Alphabet::BASE62)
+        .randomness_length(16)
+        .prefix("ord_")
+        .checksum(Checksum::LuhnModN);
+}
+
+rowid_type! {
+    struct InvalidID = RowIDWithConfig::new().char_list("ABC");
+}
+
+#[test]
+fn test_rowid_type_generate() {
+    let user: UserID = UserID::generate();
+    let order: OrderID = OrderID::generate();
+
+    assert!(user.as_str().len() == 32);
+    assert!(order.as_str().starts_with("ord_"));
+    assert!(order.as_str().chars().count() == 4 + 9 + 16 + 1);
+    assert!(order.as_row_id().randomness().len() == 16);
+}
+
+#[test]
+fn test_rowid_type_parse() {
+    let order: OrderID = OrderID::generate();
+    let parsed: OrderID = order.as_str().parse().unwrap();
+
+    assert!(parsed == order);
+    assert!(parsed.timestamp() == order.timestamp());
+    assert!(parsed.to_string() == order.as_str());
+}
+
+#[test]
+fn test_rowid_type_parse_error() {
+    let user: UserID = UserID::generate();
+
+    let err: RowIDError = match user.as_str().parse::<OrderID>() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::PrefixMismatch { position: 0 });
+}
+
+#[test]
+fn test_rowid_type_try_from() {
+    let id: RowID = OrderID::config().rowid_typed();
+    let order: OrderID = OrderID::try_from(id.clone()).unwrap();
+
+    assert!(RowID::from(order) == id);
+    assert!(UserID::try_from(id).is_err());
+}
+
+#[test]
+fn test_rowid_type_hash() {
+    let ids: HashSet<UserID> = (0..100).map(|_| UserID::generate()).collect();
+
+    assert!(ids.len() == 100);
+}
+
+#[test]
+#[should_panic(expected = "Invalid configuration of `InvalidID`")]
+fn test_rowid_type_invalid_config() {
+    InvalidID::generate();
+}
+
+mod hygiene {
+    #![allow(dead_code, unused_macros)]
+
+    use rowid::rowid_type;
+
+    type Result<T> = ::core::result::Result<T, ()>;
+
+    struct String;
+
+    trait AsRef {}
+
+    trait TryFrom {}
+
+    trait From {}
+
+    macro_rules! panic {
+        () => {};
+    }
+
+    rowid_type! {
+        pub struct HygieneID;
+    }
+
+    #[test]
+    fn test_rowid_type_hygiene() {
+        let id: HygieneID = HygieneID::generate();
+        let parsed: HygieneID = id.as_str().parse().unwrap();
+
+        assert!(parsed == id);
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::HashSet, sync::Arc, thread, time::Duration};
+
+use rowid::{
+    base::{RowID, RowIDError},
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{
+        Checksum, DecodedParts, RowIDWithConfig, RowIDWithConfigResult,
+        SequenceExhausted, SequenceGenerator,
+    },
+};
+
+#[test]
+fn test_sequence_rowid() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(7)
+        .sequence_length(2)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    assert!(generator.rowid().unwrap() == "00000000Z80700");
+    assert!(generator.rowid().unwrap() == "00000000Z80701");
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(generator.rowid().unwrap() == "00000000Z90700");
+}
+
+#[test]
+fn test_sequence_rowid_typed() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(7)
+        .prefix("evt_")
+        .checksum(Checksum::Crockford)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    let first: RowID = generator.rowid_typed().unwrap();
+    let second: RowID = generator.rowid_typed().unwrap();
+
+    assert!(first < second);
+    assert!(first.prefix() == "evt_");
+    assert!(first.encoded_node() == "07");
+    assert!(first.randomness() == "000");
+    assert!(second.randomness() == "001");
+    assert!(first.timestamp() == timestamp_to_system_time(1_000));
+    assert!(generator.parse(second.as_str()).unwrap() == second);
+
+    let parts: DecodedParts = rwc.decode_parts(second.as_str()).unwrap();
+
+    assert!(parts.node_id == Some(7));
+    assert!(parts.randomness == "001");
+}
+
+#[test]
+fn test_sequence_clock_backwards() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    let first: String = generator.rowid().unwrap();
+
+    clock.set(timestamp_to_system_time(500));
+
+    let second: String = generator.rowid().unwrap();
+
+    assert!(first < second);
+}
+
+#[test]
+fn test_sequence_overflow_error() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .sequence_length(1)
+        .sequence_exhausted(SequenceExhausted::Error)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    for _ in 0..32 {
+        assert!(generator.rowid().is_ok());
+    }
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::SequenceOverflow);
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(generator.rowid().unwrap() == "00000000Z90");
+}
+
+#[test]
+fn test_sequence_overflow_wait() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .sequence_length(0)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    assert!(generator.rowid().unwrap() == "00000000Z8");
+
+    let handle: thread::JoinHandle<()> = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        clock.advance(Duration::from_millis(1));
+    });
+
+    assert!(generator.rowid().unwrap() == "00000000Z9");
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_sequence_threads() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().node_id(3).done().unwrap();
+    let generator: Arc<SequenceGenerator> = Arc::new(rwc.sequence());
+
+    let handles: Vec<thread::JoinHandle<Vec<String>>> = (0..8)
+        .map(|_| {
+            let generator: Arc<SequenceGenerator> = generator.clone();
+
+            thread::spawn(move || {
+                (0..1_000).map(|_| generator.rowid().unwrap()).collect()
+            })
+        })
+        .collect();
+
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for handle in handles {
+        let ids: Vec<String> = handle.join().unwrap();
+
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        seen.extend(ids);
+    }
+
+    assert!(seen.len() == 8_000);
+}
+
+#[test]
+fn test_sequence_timestamp_length_overflow() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(3)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SequenceGenerator = rwc.sequence();
+
+    assert!(generator.rowid().is_ok());
+
+    clock.advance(Duration::from_secs(1_000_000));
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampOverflow { timestamp: Some(1_000_001_000) }
+    );
+}
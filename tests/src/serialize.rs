@@ -0,0 +1,177 @@
+use std::time::{Duration, SystemTime};
+
+use rowid::{
+    base::{PrefixedRowID, RowID, RowIDPrefix, rowid_prefixed, rowid_typed},
+    rowid_type,
+    with_config::{
+        Alphabet, Checksum, RowIDWithConfig, RowIDWithConfigResult,
+        RowIDWithConfigState, VerifyPolicy,
+    },
+};
+
+struct User;
+
+impl RowIDPrefix for User {
+    const PREFIX: &'static str = "user_";
+}
+
+rowid_type! {
+    struct OrderID = RowIDWithConfig::new()
+        .prefix("ord_")
+        .checksum(Checksum::Crockford);
+}
+
+rowid_type! {
+    struct TokenID = RowIDWithConfig::new().alphabet(Alphabet::BASE62);
+}
+
+#[test]
+fn test_serialize_rowid_json() {
+    let id: RowID = rowid_typed();
+    let json: String = serde_json::to_string(&id).unwrap();
+
+    assert!(json == format!("\"{}\"", id));
+    assert!(serde_json::from_str::<RowID>(&json).unwrap() == id);
+}
+
+#[test]
+fn test_serialize_rowid_json_error() {
+    assert!(serde_json::from_str::<RowID>("\"01JVS\"").is_err());
+    assert!(serde_json::from_str::<RowID>("\"01JVS2Y5RI\"").is_err());
+}
+
+#[test]
+fn test_serialize_rowid_bincode() {
+    let id: RowID = rowid_typed();
+    let bytes: Vec<u8> = bincode::serialize(&id).unwrap();
+
+    assert!(bytes.len() == 8 + 20);
+    assert!(bincode::deserialize::<RowID>(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_serialize_rowid_not_default_error() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(serde_json::to_string(&id).is_err());
+    assert!(bincode::serialize(&id).is_err());
+
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().prefix("user_").done().unwrap();
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(serde_json::to_string(&id).is_err());
+    assert!(bincode::serialize(&id).is_err());
+
+    let prefixed: PrefixedRowID<User> = PrefixedRowID::try_from(id).unwrap();
+    let json: String = serde_json::to_string(&prefixed).unwrap();
+    let bytes: Vec<u8> = bincode::serialize(&prefixed).unwrap();
+
+    assert!(bytes.len() == 8 + 20);
+    assert!(
+        serde_json::from_str::<PrefixedRowID<User>>(&json).unwrap() == prefixed
+    );
+    assert!(
+        bincode::deserialize::<PrefixedRowID<User>>(&bytes).unwrap()
+            == prefixed
+    );
+
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .prefix("user_")
+        .checksum(Checksum::Crockford)
+        .done()
+        .unwrap();
+
+    let prefixed: PrefixedRowID<User> =
+        PrefixedRowID::try_from(rwc.rowid_typed()).unwrap();
+
+    assert!(serde_json::to_string(&prefixed).is_err());
+    assert!(bincode::serialize(&prefixed).is_err());
+}
+
+#[test]
+fn test_serialize_prefixed_rowid() {
+    let id: PrefixedRowID<User> = rowid_prefixed();
+
+    let json: String = serde_json::to_string(&id).unwrap();
+
+    assert!(json == format!("\"{}\"", id));
+    assert!(serde_json::from_str::<PrefixedRowID<User>>(&json).unwrap() == id);
+
+    let bytes: Vec<u8> = bincode::serialize(&id).unwrap();
+
+    assert!(bytes.len() == 8 + 20);
+    assert!(bincode::deserialize::<PrefixedRowID<User>>(&bytes).unwrap() == id);
+}
+
+#[test]
+fn test_serialize_rowid_type() {
+    let order: OrderID = OrderID::generate();
+
+    let json: String = serde_json::to_string(&order).unwrap();
+
+    assert!(json == format!("\"{}\"", order));
+    assert!(serde_json::from_str::<OrderID>(&json).unwrap() == order);
+
+    let bytes: Vec<u8> = bincode::serialize(&order).unwrap();
+
+    assert!(bytes.len() == 8 + 20);
+    assert!(bincode::deserialize::<OrderID>(&bytes).unwrap() == order);
+}
+
+#[test]
+fn test_serialize_rowid_type_not_compact() {
+    let token: TokenID = TokenID::generate();
+    let bytes: Vec<u8> = bincode::serialize(&token).unwrap();
+
+    assert!(bytes.len() == 8 + token.as_str().len());
+    assert!(bincode::deserialize::<TokenID>(&bytes).unwrap() == token);
+}
+
+#[test]
+fn test_serialize_state() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .alphabet(Alphabet::HEX_LOWER)
+        .randomness_length(16)
+        .prefix("user_")
+        .checksum(Checksum::LuhnModN)
+        .verify_policy(VerifyPolicy::new().max_age(Duration::from_secs(60)))
+        .done()
+        .unwrap();
+
+    let json: String = serde_json::to_string(&rwc.state).unwrap();
+    let state: RowIDWithConfigState = serde_json::from_str(&json).unwrap();
+
+    let restored: RowIDWithConfigResult =
+        RowIDWithConfig::from(state).done().unwrap();
+
+    let id: String = rwc.rowid();
+
+    assert!(restored.state.timestamp_length == rwc.state.timestamp_length);
+    assert!(restored.parse(&id).unwrap() == rwc.parse(&id).unwrap());
+    assert!(restored.try_verify(&id).is_ok());
+    assert!(rwc.try_verify(restored.rowid()).is_ok());
+}
+
+#[test]
+fn test_serialize_state_epoch_before_unix_epoch() {
+    let epoch: SystemTime =
+        SystemTime::UNIX_EPOCH - Duration::from_secs(86_400 * 365 * 100);
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().epoch(epoch).done().unwrap();
+
+    let json: String = serde_json::to_string(&rwc.state).unwrap();
+    let state: RowIDWithConfigState = serde_json::from_str(&json).unwrap();
+
+    assert!(json.contains("\"epoch\":-3153600000000"));
+    assert!(state.epoch == epoch);
+
+    let bytes: Vec<u8> = bincode::serialize(&rwc.state).unwrap();
+    let state: RowIDWithConfigState = bincode::deserialize(&bytes).unwrap();
+
+    assert!(state.epoch == epoch);
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashSet, sync::Arc, thread, time::Duration};
+
+use rowid::{
+    base::{RowID, RowIDError},
+    time::{MockClock, Precision, timestamp_to_system_time},
+    with_config::{
+        RowIDWithConfig, RowIDWithConfigResult, SequenceExhausted,
+        SharedGenerator,
+    },
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_shared_send_sync() {
+    assert_send_sync::<SharedGenerator>();
+    assert_send_sync::<RowIDWithConfigResult>();
+}
+
+#[test]
+fn test_shared_rowid() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .node_id(7)
+        .sequence_length(2)
+        .randomness_length(0)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    assert!(generator.rowid().unwrap() == "00000000Z80700");
+    assert!(generator.rowid().unwrap() == "00000000Z80701");
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(generator.rowid().unwrap() == "00000000Z90700");
+}
+
+#[test]
+fn test_shared_rowid_typed() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(8)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    let first: RowID = generator.rowid_typed().unwrap();
+    let second: RowID = generator.rowid_typed().unwrap();
+
+    assert!(first < second);
+    assert!(first.as_str().len() == 21);
+    assert!(first.randomness().starts_with("000"));
+    assert!(second.randomness().starts_with("001"));
+    assert!(first.timestamp() == timestamp_to_system_time(1_000));
+    assert!(generator.parse(second.as_str()).unwrap() == second);
+}
+
+#[test]
+fn test_shared_clock_backwards() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    let first: String = generator.rowid().unwrap();
+
+    clock.set(timestamp_to_system_time(500));
+
+    let second: String = generator.rowid().unwrap();
+
+    assert!(first < second);
+}
+
+#[test]
+fn test_shared_overflow_error() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .sequence_length(1)
+        .randomness_length(0)
+        .sequence_exhausted(SequenceExhausted::Error)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    for _ in 0..32 {
+        assert!(generator.rowid().is_ok());
+    }
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::SequenceOverflow);
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(generator.rowid().unwrap() == "00000000Z90");
+}
+
+#[test]
+fn test_shared_overflow_wait() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .sequence_length(0)
+        .randomness_length(0)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    assert!(generator.rowid().unwrap() == "00000000Z8");
+
+    let handle: thread::JoinHandle<()> = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        clock.advance(Duration::from_millis(1));
+    });
+
+    assert!(generator.rowid().unwrap() == "00000000Z9");
+
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_shared_sequence_length_error() {
+    let err: RowIDError =
+        match RowIDWithConfig::new().sequence_length(13).done() {
+            | Ok(_) => panic!("expected an error"),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::SequenceLength { max: 12, actual: 13 });
+
+    let mut rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().sequence_length(12).done().unwrap();
+
+    assert!(rwc.shared().is_ok());
+
+    rwc.state.sequence_length = 13;
+
+    let err: RowIDError = match rwc.shared() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::SequenceLength { max: 12, actual: 13 });
+}
+
+#[test]
+fn test_shared_precision() {
+    for precision in [Precision::Microseconds, Precision::Nanoseconds] {
+        let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+            .precision(precision)
+            .timestamp_length(13)
+            .randomness_length(4)
+            .done()
+            .unwrap();
+        let generator: SharedGenerator = rwc.shared().unwrap();
+
+        let first: RowID = generator.rowid_typed().unwrap();
+        let second: RowID = generator.rowid_typed().unwrap();
+
+        assert!(first < second);
+        assert!(first.timestamp() <= second.timestamp());
+        assert!(generator.parse(second.as_str()).unwrap() == second);
+    }
+}
+
+#[test]
+fn test_shared_threads() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(4).done().unwrap();
+    let generator: Arc<SharedGenerator> = Arc::new(rwc.shared().unwrap());
+
+    let handles: Vec<thread::JoinHandle<Vec<String>>> = (0..8)
+        .map(|_| {
+            let generator: Arc<SharedGenerator> = generator.clone();
+
+            thread::spawn(move || {
+                (0..1_000).map(|_| generator.rowid().unwrap()).collect()
+            })
+        })
+        .collect();
+
+    let mut seen: HashSet<String> = HashSet::new();
+
+    for handle in handles {
+        let ids: Vec<String> = handle.join().unwrap();
+
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+        seen.extend(ids);
+    }
+
+    assert!(seen.len() == 8_000);
+}
+
+#[test]
+fn test_shared_timestamp_length_overflow() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(3)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+    let generator: SharedGenerator = rwc.shared().unwrap();
+
+    assert!(generator.rowid().is_ok());
+
+    clock.advance(Duration::from_secs(1_000_000));
+
+    let err: RowIDError = match generator.rowid() {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampOverflow { timestamp: Some(1_000_001_000) }
+    );
+}
@@ -0,0 +1,79 @@
+//! Run with `RUSTFLAGS="--cfg loom" cargo test -p tests --release shared_loom`.
+
+use std::sync::Arc;
+
+use loom::thread;
+use rowid::{
+    base::RowIDError,
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{
+        RowIDWithConfig, RowIDWithConfigResult, SequenceExhausted,
+        SharedGenerator,
+    },
+};
+
+fn generator(
+    sequence_length: usize,
+    on_exhausted: SequenceExhausted,
+) -> Arc<SharedGenerator> {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .sequence_length(sequence_length)
+        .randomness_length(0)
+        .sequence_exhausted(on_exhausted)
+        .clock(MockClock::new(timestamp_to_system_time(1_000)))
+        .done()
+        .unwrap();
+
+    Arc::new(rwc.shared().unwrap())
+}
+
+#[test]
+fn test_shared_loom_monotonic() {
+    loom::model(|| {
+        let generator: Arc<SharedGenerator> =
+            generator(1, SequenceExhausted::Error);
+
+        let handle: thread::JoinHandle<Vec<String>> = {
+            let generator: Arc<SharedGenerator> = generator.clone();
+
+            thread::spawn(move || {
+                (0..2).map(|_| generator.rowid().unwrap()).collect()
+            })
+        };
+
+        let mut ids: Vec<String> =
+            (0..2).map(|_| generator.rowid().unwrap()).collect();
+
+        assert!(ids[0] < ids[1]);
+
+        let other: Vec<String> = handle.join().unwrap();
+
+        assert!(other[0] < other[1]);
+
+        ids.extend(other);
+        ids.sort();
+        ids.dedup();
+
+        assert!(ids.len() == 4);
+    });
+}
+
+#[test]
+fn test_shared_loom_exhausted() {
+    loom::model(|| {
+        let generator: Arc<SharedGenerator> =
+            generator(0, SequenceExhausted::Error);
+
+        let handle: thread::JoinHandle<Result<String, RowIDError>> = {
+            let generator: Arc<SharedGenerator> = generator.clone();
+
+            thread::spawn(move || generator.rowid())
+        };
+
+        let results: [Result<String, RowIDError>; 2] =
+            [generator.rowid(), handle.join().unwrap()];
+
+        assert!(results.iter().filter(|r| r.is_ok()).count() == 1);
+        assert!(results.contains(&Err(RowIDError::SequenceOverflow)));
+    });
+}
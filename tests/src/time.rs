@@ -1,6 +1,9 @@
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
-use rowid::time::{system_time_to_timestamp, timestamp_to_system_time};
+use rowid::time::{
+    Clock, MockClock, Precision, system_time_to_timestamp,
+    timestamp_to_system_time,
+};
 
 // system_time_to_timestamp
 
@@ -27,3 +30,48 @@ fn test_timestamp_system_time() {
     let timestamp: usize = system_time_to_timestamp(system_time);
     assert!(ts == timestamp);
 }
+
+// MockClock
+
+#[test]
+fn test_mock_clock() {
+    let clock: MockClock = MockClock::new(SystemTime::UNIX_EPOCH);
+    assert!(clock.now() == SystemTime::UNIX_EPOCH);
+
+    clock.advance(Duration::from_millis(5));
+    assert!(system_time_to_timestamp(clock.now()) == 5);
+
+    clock.set(timestamp_to_system_time(1_000));
+    assert!(system_time_to_timestamp(clock.now()) == 1_000);
+}
+
+#[test]
+fn test_mock_clock_shared() {
+    let clock: MockClock = MockClock::default();
+    let cloned: MockClock = clock.clone();
+
+    clock.advance(Duration::from_millis(10));
+
+    assert!(system_time_to_timestamp(cloned.now()) == 10);
+}
+
+// Precision
+
+#[test]
+fn test_precision() {
+    let duration: Duration = Duration::from_nanos(1_234_567_891);
+
+    assert!(Precision::Seconds.duration_to_timestamp(duration) == 1);
+    assert!(Precision::Milliseconds.duration_to_timestamp(duration) == 1_234);
+    assert!(
+        Precision::Microseconds.duration_to_timestamp(duration) == 1_234_567
+    );
+    assert!(
+        Precision::Nanoseconds.duration_to_timestamp(duration) == 1_234_567_891
+    );
+    assert!(
+        Precision::Microseconds.timestamp_to_duration(1_234_567)
+            == Some(Duration::from_micros(1_234_567))
+    );
+    assert!(Precision::Seconds.timestamp_to_duration(u128::MAX).is_none());
+}
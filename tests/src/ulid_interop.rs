@@ -0,0 +1,105 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use rowid::{
+    base::{RowID, RowIDError},
+    ulid,
+    with_config::{MonotonicGenerator, RowIDWithConfig, RowIDWithConfigResult},
+};
+
+// Test vectors of the reference implementation.
+const VECTORS: [(u128, &str); 5] = [
+    (0x41414141414141414141414141414141, "21850M2GA1850M2GA1850M2GA1"),
+    (0x4d4e385051444a59454234335a413756, "2D9RW50MA499CMAGHM6DD42DTP"),
+    (0x0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f, "0F1W7GY3RF1W7GY3RF1W7GY3RF"),
+    (0, "00000000000000000000000000"),
+    (u128::MAX, "7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+];
+
+#[test]
+fn test_ulid_vectors() {
+    for (value, encoded) in VECTORS {
+        assert!(ulid::to_u128(encoded).unwrap() == value);
+        assert!(ulid::to_u128(encoded.to_lowercase()).unwrap() == value);
+        assert!(ulid::from_u128(value).as_str() == encoded);
+        assert!(ulid::to_bytes(encoded).unwrap() == value.to_be_bytes());
+        assert!(ulid::from_bytes(value.to_be_bytes()).as_str() == encoded);
+    }
+}
+
+#[test]
+fn test_ulid_reference_implementation() {
+    for _ in 0..100 {
+        let id: String = ulid::ulid();
+        let reference: ::ulid::Ulid = ::ulid::Ulid::from_string(&id).unwrap();
+
+        assert!(ulid::to_u128(&id).unwrap() == reference.0);
+        assert!(ulid::to_bytes(&id).unwrap() == reference.to_bytes());
+        assert!(ulid::from_u128(reference.0).as_str() == id);
+        assert!(
+            ulid::parse(&id).unwrap().timestamp()
+                == UNIX_EPOCH + Duration::from_millis(reference.timestamp_ms())
+        );
+    }
+}
+
+#[test]
+fn test_ulid_parse() {
+    let id: RowID = ulid::parse("01arz3ndektsv4rrffq69g5fav").unwrap();
+
+    assert!(id.as_str() == "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    assert!(id.encoded_timestamp() == "01ARZ3NDEK");
+    assert!(id.randomness() == "TSV4RRFFQ69G5FAV");
+    assert!(id.timestamp() == rowid::base::decode(id.as_str()).unwrap());
+}
+
+#[test]
+fn test_ulid_errors() {
+    let err: RowIDError = match ulid::to_u128("01ARZ3NDEK") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 26, actual: 10 });
+
+    let err: RowIDError = match ulid::to_u128("01ARZ3NDEKTSV4RRFFQ69G5FAU") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: 'U', position: 25 });
+
+    let err: RowIDError = match ulid::to_u128("80000000000000000000000000") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::TimestampOverflow { timestamp: Some(1 << 48) });
+}
+
+#[test]
+fn test_ulid_with_config() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::ulid().done().unwrap();
+
+    let id: String = rwc.rowid();
+
+    assert!(id.len() == 26);
+    assert!(::ulid::Ulid::from_string(&id).is_ok());
+    assert!(rwc.decode(id.to_lowercase()).unwrap() == rwc.decode(&id).unwrap());
+    assert!(ulid::parse(&id).unwrap() == rwc.parse(&id).unwrap());
+}
+
+#[test]
+fn test_ulid_monotonic() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::ulid().done().unwrap();
+    let mut generator: MonotonicGenerator = rwc.monotonic();
+
+    let mut previous: u128 = 0;
+
+    for _ in 0..100 {
+        let value: u128 = ulid::to_u128(generator.rowid().unwrap()).unwrap();
+
+        assert!(value > previous);
+
+        previous = value;
+    }
+}
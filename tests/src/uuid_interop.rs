@@ -0,0 +1,114 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use rowid::{
+    base::{RowID, RowIDError, rowid},
+    uuid::{from_uuid, to_uuid},
+    with_config::{RowIDWithConfig, RowIDWithConfigResult},
+};
+
+#[test]
+fn test_uuid_round_trip() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::uuid_v7().done().unwrap();
+
+    for _ in 0..100 {
+        let id: String = rwc.rowid();
+        let uuid: ::uuid::Uuid = to_uuid(&id).unwrap();
+
+        assert!(uuid.get_version_num() == 7);
+        assert!(uuid.get_variant() == ::uuid::Variant::RFC4122);
+        assert!(from_uuid(uuid).unwrap().as_str() == id);
+    }
+}
+
+#[test]
+fn test_uuid_timestamp() {
+    for _ in 0..100 {
+        let id: String = rowid();
+        let uuid: ::uuid::Uuid = to_uuid(&id).unwrap();
+        let (seconds, nanos): (u64, u32) =
+            uuid.get_timestamp().unwrap().to_unix();
+
+        assert!(
+            UNIX_EPOCH + Duration::new(seconds, nanos)
+                == rowid::base::decode(&id).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_uuid_reference_implementation() {
+    for _ in 0..100 {
+        let uuid: ::uuid::Uuid = ::uuid::Uuid::now_v7();
+        let id: RowID = from_uuid(uuid).unwrap();
+        let (seconds, nanos): (u64, u32) =
+            uuid.get_timestamp().unwrap().to_unix();
+
+        assert!(id.as_str().len() == 24);
+        assert!(id.timestamp() == UNIX_EPOCH + Duration::new(seconds, nanos));
+        assert!(id.timestamp() == rowid::base::decode(id.as_str()).unwrap());
+
+        // The last 4 bits of the randomness are dropped.
+        let back: ::uuid::Uuid = to_uuid(id.as_str()).unwrap();
+
+        assert!(back.as_u128() >> 4 == uuid.as_u128() >> 4);
+    }
+}
+
+#[test]
+fn test_uuid_short_randomness() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(4).done().unwrap();
+
+    let id: String = rwc.rowid();
+    let back: RowID = from_uuid(to_uuid(&id).unwrap()).unwrap();
+
+    assert!(back.as_str() == format!("{}0000000000", id));
+}
+
+#[test]
+fn test_uuid_errors() {
+    let err: RowIDError = match from_uuid(::uuid::Uuid::from_u128(
+        0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8,
+    )) {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::UuidVersion { version: 4 });
+
+    let err: RowIDError = match to_uuid("01ARZ3NDE") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 10, actual: 9 });
+
+    let err: RowIDError = match to_uuid("01ARZ3NDEKTSV4RRFFQ69U") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: 'U', position: 21 });
+
+    let err: RowIDError = match to_uuid("80000000000000000000") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::TimestampOverflow { timestamp: Some(1 << 48) });
+
+    let err: RowIDError = match to_uuid("01JVS2Y5R8ABCDEFGHJKMNPQ~~~~ !!") {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: '~', position: 24 });
+
+    let err: RowIDError = match to_uuid(format!("{}0", rowid())) {
+        | Ok(_) => panic!("expected an error"),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 32, actual: 33 });
+    assert!(to_uuid(rowid()).is_ok());
+}
@@ -0,0 +1,140 @@
+use std::time::{Duration, SystemTime};
+
+use rowid::{
+    base::{RowIDError, Verified},
+    time::{MockClock, timestamp_to_system_time},
+    with_config::{RowIDWithConfig, RowIDWithConfigResult, VerifyPolicy},
+};
+
+fn with_policy(
+    clock: &MockClock,
+    policy: VerifyPolicy,
+) -> RowIDWithConfigResult {
+    RowIDWithConfig::new()
+        .randomness_length(6)
+        .clock(clock.clone())
+        .verify_policy(policy)
+        .done()
+        .unwrap()
+}
+
+#[test]
+fn test_verify_policy_default() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(10_000));
+    let rwc: RowIDWithConfigResult = with_policy(&clock, VerifyPolicy::new());
+
+    assert!(rwc.try_verify("0000000000").is_ok());
+    assert!(rwc.try_verify("0000000000!!!!!!!!!!!!!!!!!!!!!!").is_ok());
+
+    let future: String =
+        rwc.try_generate(timestamp_to_system_time(1_000_000), None).unwrap();
+    let verified: Verified = rwc.try_verify(future).unwrap();
+
+    assert!(!verified.natural);
+}
+
+#[test]
+fn test_verify_policy_exact_length() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(10_000));
+    let rwc: RowIDWithConfigResult =
+        with_policy(&clock, VerifyPolicy::new().exact_length(true));
+
+    assert!(rwc.try_verify(rwc.rowid()).is_ok());
+
+    let err: RowIDError = match rwc.try_verify("0000000000") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 16, actual: 10 });
+
+    let err: RowIDError = match rwc.try_verify("0".repeat(500)) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 16, actual: 500 });
+    assert!(
+        err.to_string()
+            == "Encoded length does not match the expected length (expected 16, got 500)"
+    );
+}
+
+#[test]
+fn test_verify_policy_check_randomness() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(10_000));
+    let rwc: RowIDWithConfigResult =
+        with_policy(&clock, VerifyPolicy::new().check_randomness(true));
+
+    assert!(rwc.try_verify(rwc.rowid()).is_ok());
+
+    let err: RowIDError = match rwc.try_verify("0000000000ABC!EF") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::InvalidEncoded { character: '!', position: 13 });
+}
+
+#[test]
+fn test_verify_policy_max_future_skew() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(10_000));
+    let rwc: RowIDWithConfigResult = with_policy(
+        &clock,
+        VerifyPolicy::new().max_future_skew(Duration::from_secs(5)),
+    );
+
+    let within: String =
+        rwc.try_generate(timestamp_to_system_time(15_000), None).unwrap();
+    let beyond: String =
+        rwc.try_generate(timestamp_to_system_time(15_001), None).unwrap();
+
+    assert!(rwc.try_verify(within).is_ok());
+
+    let err: RowIDError = match rwc.try_verify(beyond) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampInFuture {
+            ahead: Duration::from_millis(5_001)
+        }
+    );
+}
+
+#[test]
+fn test_verify_policy_age() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(10_000));
+    let rwc: RowIDWithConfigResult = with_policy(
+        &clock,
+        VerifyPolicy::new()
+            .min_age(Duration::from_secs(1))
+            .max_age(Duration::from_secs(5)),
+    );
+
+    let time: SystemTime = timestamp_to_system_time(8_000);
+    let id: String = rwc.try_generate(time, None).unwrap();
+
+    assert!(rwc.try_verify(&id).unwrap().time == time);
+
+    clock.set(timestamp_to_system_time(8_500));
+
+    let err: RowIDError = match rwc.try_verify(&id) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampTooNew { age: Duration::from_millis(500) }
+    );
+
+    clock.set(timestamp_to_system_time(14_000));
+
+    let err: RowIDError = match rwc.try_verify(&id) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::TimestampTooOld { age: Duration::from_secs(6) });
+}
@@ -1,20 +1,27 @@
-use std::{io, time::SystemTime};
+use std::{
+    thread,
+    time::{Duration, SystemTime},
+};
 
 use rowid::{
-    base::{GenerateResult, RowIDError, VerifyResult},
-    time::system_time_to_timestamp,
-    with_config::{RowIDWithConfig, RowIDWithConfigResult},
+    base::{GenerateResult, RowID, RowIDError, Verified, VerifyResult},
+    time::{
+        MockClock, Precision, system_time_to_timestamp,
+        timestamp_to_system_time,
+    },
+    with_config::{
+        MonotonicGenerator, RowIDIter, RowIDWithConfig, RowIDWithConfigResult,
+    },
 };
 
 #[test]
 fn test_rowid_with_config_char_list_length_error() {
-    let err: io::Error = match RowIDWithConfig::new().char_list("ABC").done() {
+    let err: RowIDError = match RowIDWithConfig::new().char_list("ABC").done() {
         | Ok(_) => return assert!(false),
         | Err(e) => e,
     };
 
-    assert!(err.kind() == io::ErrorKind::InvalidInput);
-    assert!(err.to_string() == RowIDError::CharListLength.as_str());
+    assert!(err == RowIDError::CharListLength { expected: 28, actual: 3 });
 }
 
 #[test]
@@ -69,6 +76,7 @@ fn test_rowid_with_config_encode_decode() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_rowid_with_config_generate() {
     let rwc: RowIDWithConfigResult =
         RowIDWithConfig::new().randomness_length(6).done().unwrap();
@@ -86,6 +94,7 @@ fn test_rowid_with_config_generate() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_rowid_with_config_verify() {
     let rwc: RowIDWithConfigResult =
         RowIDWithConfig::new().randomness_length(6).done().unwrap();
@@ -102,3 +111,408 @@ fn test_rowid_with_config_verify() {
     });
     assert!(verified.natural == Some(true));
 }
+
+#[test]
+fn test_rowid_with_config_try_generate_verify() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().randomness_length(6).done().unwrap();
+
+    let current: SystemTime = SystemTime::now();
+    let id: String = rwc.try_generate(current, None).unwrap();
+    let verified: Verified = rwc.try_verify(&id).unwrap();
+
+    assert!(id.len() == 16);
+    assert!(
+        system_time_to_timestamp(verified.time)
+            == system_time_to_timestamp(current)
+    );
+    assert!(verified.natural);
+}
+
+#[test]
+fn test_rowid_with_config_clock() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(0));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .randomness_length(6)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    assert!(rwc.rowid().starts_with("0000000000"));
+
+    clock.advance(Duration::from_millis(1));
+
+    assert!(rwc.rowid().starts_with("0000000001"));
+}
+
+#[test]
+fn test_rowid_with_config_verify_clock() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().clock(clock.clone()).done().unwrap();
+
+    let id: String =
+        rwc.try_generate(timestamp_to_system_time(2_000), None).unwrap();
+
+    assert!(!rwc.try_verify(&id).unwrap().natural);
+
+    clock.advance(Duration::from_secs(2));
+
+    assert!(rwc.try_verify(&id).unwrap().natural);
+}
+
+#[test]
+fn test_rowid_with_config_epoch() {
+    let epoch: SystemTime = timestamp_to_system_time(1_000_000);
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().epoch(epoch).done().unwrap();
+
+    assert!(rwc.encode(epoch).unwrap() == "0000000000");
+    assert!(
+        rwc.encode(epoch + Duration::from_millis(1)).unwrap() == "0000000001"
+    );
+    assert!(
+        rwc.decode("0000000001").unwrap() == epoch + Duration::from_millis(1)
+    );
+}
+
+#[test]
+fn test_rowid_with_config_epoch_before_unix_epoch() {
+    let epoch: SystemTime =
+        SystemTime::UNIX_EPOCH - Duration::from_secs(86_400 * 365 * 100);
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().epoch(epoch).done().unwrap();
+
+    let historical: SystemTime =
+        SystemTime::UNIX_EPOCH - Duration::from_secs(86_400);
+    let id: String = rwc.try_generate(historical, None).unwrap();
+
+    assert!(rwc.decode(&id).unwrap() == historical);
+    assert!(rwc.try_verify(&id).unwrap().natural);
+}
+
+#[test]
+fn test_rowid_with_config_epoch_error() {
+    let epoch: SystemTime = timestamp_to_system_time(1_000_000);
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().epoch(epoch).done().unwrap();
+
+    let err: RowIDError = match rwc.encode(timestamp_to_system_time(0)) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::SystemTimeBeforeEpoch);
+    assert!(rwc.try_generate(timestamp_to_system_time(0), None).is_err());
+}
+
+#[test]
+fn test_rowid_with_config_epoch_rowid() {
+    let epoch: SystemTime = timestamp_to_system_time(1_000_000);
+    let clock: MockClock = MockClock::new(epoch + Duration::from_millis(1));
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().epoch(epoch).clock(clock).done().unwrap();
+
+    assert!(rwc.rowid().starts_with("0000000001"));
+    assert!(rwc.monotonic().rowid().unwrap().starts_with("0000000001"));
+}
+
+#[test]
+fn test_rowid_with_config_epoch_after_clock_error() {
+    let epoch: SystemTime = timestamp_to_system_time(1_000_000);
+    let clock: MockClock = MockClock::new(epoch - Duration::from_millis(1));
+
+    let err: RowIDError =
+        match RowIDWithConfig::new().epoch(epoch).clock(clock).done() {
+            | Ok(_) => panic!(),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::SystemTimeBeforeEpoch);
+    assert!(err.to_string() == "System time must not be before the epoch");
+}
+
+#[test]
+fn test_rowid_with_config_clock_before_epoch() {
+    let epoch: SystemTime = timestamp_to_system_time(1_000_000);
+    let clock: MockClock = MockClock::new(epoch + Duration::from_millis(1));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .epoch(epoch)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    let mut monotonic: MonotonicGenerator = rwc.monotonic();
+    let mut iter: RowIDIter = rwc.rowid_iter();
+
+    assert!(iter.next().is_some());
+
+    clock.set(epoch - Duration::from_millis(1));
+    thread::sleep(Duration::from_millis(2));
+
+    for result in [monotonic.rowid(), rwc.sequence().rowid()] {
+        let err: RowIDError = match result {
+            | Ok(_) => panic!(),
+            | Err(e) => e,
+        };
+
+        assert!(err == RowIDError::SystemTimeBeforeEpoch);
+    }
+
+    let err: RowIDError = match rwc.shared() {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::SystemTimeBeforeEpoch);
+
+    assert!(iter.next().is_none());
+    assert!(rwc.rowid_iter().next().is_none());
+    assert!(rwc.rowid().starts_with("0000000000"));
+}
+
+#[test]
+fn test_rowid_with_config_precision() {
+    let system_time: SystemTime =
+        SystemTime::UNIX_EPOCH + Duration::from_nanos(1_234_567_891);
+
+    for (precision, expected) in [
+        (Precision::Seconds, Duration::from_secs(1)),
+        (Precision::Milliseconds, Duration::from_millis(1_234)),
+        (Precision::Microseconds, Duration::from_micros(1_234_567)),
+        (Precision::Nanoseconds, Duration::from_nanos(1_234_567_891)),
+    ] {
+        let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+            .precision(precision)
+            .timestamp_length(13)
+            .done()
+            .unwrap();
+
+        let decoded: SystemTime =
+            rwc.decode(rwc.encode(system_time).unwrap()).unwrap();
+
+        assert!(decoded == SystemTime::UNIX_EPOCH + expected);
+    }
+}
+
+#[test]
+fn test_rowid_with_config_precision_seconds() {
+    let rwc: RowIDWithConfigResult =
+        RowIDWithConfig::new().precision(Precision::Seconds).done().unwrap();
+
+    assert!(
+        rwc.encode(timestamp_to_system_time(1_999)).unwrap() == "0000000001"
+    );
+}
+
+#[test]
+fn test_rowid_with_config_precision_microseconds_order() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .precision(Precision::Microseconds)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    let first: String = rwc.rowid();
+
+    clock.advance(Duration::from_micros(1));
+
+    let second: String = rwc.rowid();
+
+    assert!(first[..10] < second[..10]);
+}
+
+#[test]
+fn test_rowid_with_config_timestamp_length() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(12)
+        .randomness_length(6)
+        .done()
+        .unwrap();
+
+    let current: SystemTime = SystemTime::now();
+    let id: String = rwc.try_generate(current, None).unwrap();
+
+    assert!(id.len() == 18);
+    assert!(id.starts_with("00"));
+    assert!(
+        system_time_to_timestamp(rwc.decode(&id).unwrap())
+            == system_time_to_timestamp(current)
+    );
+    assert!(rwc.rowid().len() == 18);
+}
+
+#[test]
+fn test_rowid_with_config_timestamp_overflow_error() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(10)
+        .epoch(timestamp_to_system_time(0))
+        .done()
+        .unwrap();
+
+    let max: SystemTime = rwc.max_system_time().unwrap();
+
+    assert!(max == timestamp_to_system_time(32_usize.pow(10) - 1));
+    assert!(rwc.encode(max).unwrap() == "ZZZZZZZZZZ");
+
+    let err: RowIDError = match rwc.encode(max + Duration::from_millis(1)) {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::TimestampOverflow {
+            timestamp: Some(32_u128.pow(10))
+        }
+    );
+}
+
+#[test]
+fn test_rowid_with_config_timestamp_overflow_done_error() {
+    let err: RowIDError = match RowIDWithConfig::new()
+        .precision(Precision::Microseconds)
+        .timestamp_length(10)
+        .done()
+    {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(matches!(
+        err,
+        RowIDError::TimestampOverflow { timestamp: Some(_) }
+    ));
+    assert!(
+        RowIDWithConfig::new()
+            .precision(Precision::Microseconds)
+            .timestamp_length(11)
+            .done()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_rowid_with_config_max_system_time() {
+    let max: Option<SystemTime> = RowIDWithConfig::new()
+        .precision(Precision::Seconds)
+        .timestamp_length(6)
+        .max_system_time();
+
+    assert!(
+        max == Some(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(32_u64.pow(6) - 1)
+        )
+    );
+    assert!(
+        RowIDWithConfig::new()
+            .timestamp_length(100)
+            .max_system_time()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_rowid_with_config_char_list_length_chars_error() {
+    // 28 bytes but only 14 characters.
+    let err: RowIDError =
+        match RowIDWithConfig::new().char_list("éèêëàâäôöûüùïî").done()
+        {
+            | Ok(_) => panic!(),
+            | Err(e) => e,
+        };
+
+    assert!(err == RowIDError::CharListLength { expected: 28, actual: 14 });
+}
+
+#[test]
+fn test_rowid_with_config_char_list_duplicate_error() {
+    let err: RowIDError = match RowIDWithConfig::new()
+        .char_list("0123456789ABCDEFGHJKMNPQRSTVWXYZ0")
+        .done()
+    {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::CharListDuplicate { character: '0' });
+}
+
+#[test]
+fn test_rowid_with_config_char_list_ascii_error() {
+    let char_list: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZé";
+
+    assert!(RowIDWithConfig::new().char_list(char_list).done().is_ok());
+
+    let err: RowIDError = match RowIDWithConfig::new()
+        .char_list(char_list)
+        .ascii_only(true)
+        .done()
+    {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::CharListNotAscii { character: 'é' });
+}
+
+#[test]
+fn test_rowid_with_config_char_list_sorted_error() {
+    let char_list: &str = "ZYXWVTSRQPNMKJHGFEDCBA9876543210";
+
+    assert!(RowIDWithConfig::new().char_list(char_list).done().is_ok());
+
+    let err: RowIDError = match RowIDWithConfig::new()
+        .char_list(char_list)
+        .preserve_sort_order(true)
+        .done()
+    {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(
+        err == RowIDError::CharListNotSorted { character: 'Y', position: 1 }
+    );
+}
+
+#[test]
+fn test_rowid_with_config_decode_multibyte() {
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .char_list("0123456789ABCDEFGHJKMNPQRSTVWXYZé")
+        .done()
+        .unwrap();
+
+    let current: SystemTime = SystemTime::now();
+    let id: String = rwc.try_generate(current, None).unwrap();
+
+    assert!(
+        system_time_to_timestamp(rwc.decode(&id).unwrap())
+            == system_time_to_timestamp(current)
+    );
+
+    let err: RowIDError = match rwc.decode("aéééééééé") {
+        | Ok(_) => panic!(),
+        | Err(e) => e,
+    };
+
+    assert!(err == RowIDError::EncodedLength { expected: 10, actual: 9 });
+}
+
+#[test]
+fn test_rowid_with_config_timestamp_saturates() {
+    let clock: MockClock = MockClock::new(timestamp_to_system_time(1_000));
+    let rwc: RowIDWithConfigResult = RowIDWithConfig::new()
+        .timestamp_length(3)
+        .clock(clock.clone())
+        .done()
+        .unwrap();
+
+    clock.advance(Duration::from_secs(1_000_000));
+
+    let id: RowID = rwc.rowid_typed();
+
+    assert!(id.encoded_timestamp() == "ZZZ");
+    assert!(id.timestamp() == timestamp_to_system_time(32_767));
+    assert!(rwc.rowid().starts_with("ZZZ"));
+}